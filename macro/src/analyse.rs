@@ -1,6 +1,10 @@
-use crate::{error::ExtError, UnionFn};
+use crate::{
+    config::UnionFnConfig, error::ExtError, utils::AttributeExt as _, UnionFn, UnionFnMethod,
+};
+use heck::ToUpperCamelCase as _;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::ToTokens;
+use std::collections::HashMap;
 use syn::{spanned::Spanned, Result};
 
 pub fn union_fn(args: TokenStream2, item: TokenStream2) -> TokenStream2 {
@@ -12,6 +16,8 @@ pub fn union_fn(args: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// State required for [`UnionFn`] analysis and expansion.
 #[derive(Default)]
 pub struct UnionFnState {
+    /// The configuration given via the `#[union_fn]` macro arguments.
+    config: UnionFnConfig,
     /// The shared function context if any.
     context: Option<syn::TraitItemType>,
     /// The shared output type if any.
@@ -50,9 +56,44 @@ impl SharedSignature {
     pub fn abi_span(&self) -> Span {
         self.abi.as_ref().map(|c| c.span()).unwrap_or(self.span)
     }
+
+    /// Returns the span of the shared explicit return type or the signature itself.
+    pub fn output_span(&self) -> Span {
+        match &self.output {
+            syn::ReturnType::Default => self.span,
+            syn::ReturnType::Type(_, ty) => ty.span(),
+        }
+    }
+
+    /// Returns the `output` with an explicit `-> ()` normalized to the default return type.
+    ///
+    /// # Note
+    ///
+    /// This allows to compare `fn foo()` and `fn bar() -> ()` as having the same output.
+    pub fn normalize_output(output: &syn::ReturnType) -> syn::ReturnType {
+        match output {
+            syn::ReturnType::Type(_, ty) if matches!(&**ty, syn::Type::Tuple(tuple) if tuple.elems.is_empty()) => {
+                syn::ReturnType::Default
+            }
+            output => output.clone(),
+        }
+    }
 }
 
 impl UnionFnState {
+    /// Creates a new [`UnionFnState`] with the given `config`.
+    pub fn new(config: UnionFnConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the configuration given via the `#[union_fn]` macro arguments.
+    pub fn config(&self) -> &UnionFnConfig {
+        &self.config
+    }
+
     /// Registers a context type for the `#[union_fn]` trait.
     ///
     /// # Errors
@@ -177,6 +218,26 @@ impl UnionFnState {
         }
     }
 
+    /// Returns the `const` token shared by all `#[union_fn]` methods if any.
+    pub fn get_constness(&self) -> Option<syn::token::Const> {
+        self.signature.as_ref().and_then(|sig| sig.constness)
+    }
+
+    /// Returns the `unsafe` token shared by all `#[union_fn]` methods if any.
+    pub fn get_unsafety(&self) -> Option<syn::token::Unsafe> {
+        self.signature.as_ref().and_then(|sig| sig.unsafety)
+    }
+
+    /// Returns the ABI shared by all `#[union_fn]` methods if any.
+    pub fn get_abi(&self) -> Option<syn::Abi> {
+        self.signature.as_ref().and_then(|sig| sig.abi.clone())
+    }
+
+    /// Returns the `async` token shared by all `#[union_fn]` methods if any.
+    pub fn get_asyncness(&self) -> Option<syn::token::Async> {
+        self.signature.as_ref().and_then(|sig| sig.asyncness)
+    }
+
     /// Registers an associated type of the `#[union_fn]` trait if valid.
     ///
     /// # Errors
@@ -195,6 +256,21 @@ impl UnionFnState {
         )
     }
 
+    /// Registers an associated constant of the `#[union_fn]` trait if valid.
+    ///
+    /// # Errors
+    ///
+    /// If the associated constant has no default value.
+    pub fn register_const(&mut self, item: &syn::TraitItemConst) -> syn::Result<()> {
+        if item.default.is_none() {
+            bail_spanned!(
+                item,
+                "must have a default for associated const in #[union_fn] trait"
+            )
+        }
+        Ok(())
+    }
+
     /// Registers a method signature of the `#[union_fn]` trait.
     ///
     /// # Errors
@@ -218,48 +294,89 @@ impl UnionFnState {
                     asyncness: sig.asyncness,
                     unsafety: sig.unsafety,
                     abi: sig.abi.clone(),
-                    output: sig.output.clone(),
+                    output: SharedSignature::normalize_output(&sig.output),
                 })
             }
             Some(signature) => {
                 let span = sig.span();
-                let make_err =
-                    |err_span: Option<Span>, context: &str, mis_span: Span| -> syn::Result<()> {
-                        format_err!(
-                            err_span.map(|c| c.span()).unwrap_or(span),
-                            "encountered mismatch in {context} for #[union_fn] method"
-                        )
-                        .into_combine(format_err!(mis_span, "mismatch with this method"))
-                        .into_result()
+                let make_err = |err_span: Option<Span>,
+                                actual: Option<String>,
+                                expected: Option<String>,
+                                mis_span: Span|
+                 -> syn::Result<()> {
+                    let describe = |this: &Option<String>, other: &Option<String>| match this {
+                        Some(this) => format!("`{this} fn`"),
+                        None => format!("not `{} fn`", other.as_deref().unwrap_or_default()),
                     };
+                    let actual_desc = describe(&actual, &expected);
+                    let expected_desc = describe(&expected, &actual);
+                    format_err!(
+                        err_span.unwrap_or(span),
+                        "method is {actual_desc} but previous methods are {expected_desc}"
+                    )
+                    .into_combine(format_err!(
+                        mis_span,
+                        "first method is {expected_desc} here"
+                    ))
+                    .into_result()
+                };
+                let token_str = |token: &dyn ToTokens| token.to_token_stream().to_string();
                 if sig.constness != signature.constness {
                     return make_err(
                         sig.constness.as_ref().map(Spanned::span),
-                        "constness",
+                        sig.constness.as_ref().map(|token| token_str(token)),
+                        signature.constness.as_ref().map(|token| token_str(token)),
                         signature.constness_span(),
                     );
                 }
                 if sig.asyncness != signature.asyncness {
                     return make_err(
                         sig.asyncness.as_ref().map(Spanned::span),
-                        "asyncness",
+                        sig.asyncness.as_ref().map(|token| token_str(token)),
+                        signature.asyncness.as_ref().map(|token| token_str(token)),
                         signature.asyncness_span(),
                     );
                 }
                 if sig.unsafety != signature.unsafety {
                     return make_err(
                         sig.unsafety.as_ref().map(Spanned::span),
-                        "unsafety",
+                        sig.unsafety.as_ref().map(|token| token_str(token)),
+                        signature.unsafety.as_ref().map(|token| token_str(token)),
                         signature.unsafety_span(),
                     );
                 }
                 if sig.abi != signature.abi {
                     return make_err(
                         sig.abi.as_ref().map(Spanned::span),
-                        "abi",
+                        sig.abi.as_ref().map(|abi| token_str(abi)),
+                        signature.abi.as_ref().map(|abi| token_str(abi)),
                         signature.abi_span(),
                     );
                 }
+                // With an explicit Output type the return types are checked by `register_method`.
+                let output = SharedSignature::normalize_output(&sig.output);
+                if self.output.is_none() && output != signature.output {
+                    let output_str = |output: &syn::ReturnType| match output {
+                        syn::ReturnType::Default => String::from("()"),
+                        syn::ReturnType::Type(_, ty) => token_str(ty),
+                    };
+                    let output_span = match &output {
+                        syn::ReturnType::Default => span,
+                        syn::ReturnType::Type(_, ty) => ty.span(),
+                    };
+                    let expected = output_str(&signature.output);
+                    return format_err!(
+                        output_span,
+                        "all methods must return the same type when type Output is not declared; \
+                         method returns `{}` but previous methods return `{expected}`",
+                        output_str(&output),
+                    )
+                    .into_combine(format_err!(
+                        signature.output_span(),
+                        "first method returns `{expected}` here"
+                    ))
+                    .into_result();
+                }
             }
         }
         Ok(())
@@ -273,16 +390,24 @@ impl UnionFnState {
     pub fn register_method(&mut self, item: &syn::TraitItemMethod) -> syn::Result<()> {
         self.register_sigature(&item.sig)?;
         if let Some(output) = self.get_output() {
-            let make_err = |error: &dyn ToTokens| {
-                format_err_spanned!(error, "must return Self::Output")
-                    .into_combine(format_err_spanned!(output, "since Output is defined here"))
-                    .into_result()
+            let make_err = |error: &dyn ToTokens, declared: String| {
+                format_err_spanned!(
+                    error,
+                    "method must return `Self::Output` (currently `{declared}`); \
+                    change the return type or adjust the method body"
+                )
+                .into_combine(format_err_spanned!(
+                    output,
+                    "Output is defined as `{}` here",
+                    output.to_token_stream()
+                ))
+                .into_result()
             };
             match &item.sig.output {
-                syn::ReturnType::Default => return make_err(item),
+                syn::ReturnType::Default => return make_err(item, String::from("()")),
                 syn::ReturnType::Type(_, ty) => {
                     if **ty != syn::parse_quote!(Self::Output) {
-                        return make_err(ty);
+                        return make_err(ty, ty.to_token_stream().to_string());
                     }
                 }
             }
@@ -295,11 +420,63 @@ impl UnionFnState {
                 bail_spanned!(receiver, "must not have self receiver argument")
             }
         }
-        if let Some(context) = self.get_context() {
-            let make_err = |error: &dyn ToTokens| {
+        let no_context = item
+            .attrs
+            .iter()
+            .find(|attr| attr.is_no_context_attribute());
+        if let Some(no_context) = no_context {
+            if !no_context.tokens.is_empty() {
+                bail_spanned!(no_context, "expected no arguments for #[no_context]")
+            }
+            if self.get_context().is_none() {
+                bail_spanned!(
+                    no_context,
+                    "cannot use #[no_context] for #[union_fn] trait without Context"
+                )
+            }
+        }
+        let display_names = item
+            .attrs
+            .iter()
+            .filter(|attr| attr.is_union_fn_attribute())
+            .map(UnionFnMethod::parse_display_name)
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(duplicate) = display_names.get(1) {
+            bail_spanned!(
+                duplicate,
+                "encountered duplicate #[union_fn] method attribute"
+            )
+        }
+        let context = self.get_context().filter(|_| no_context.is_none());
+        for (n, arg) in item.sig.inputs.iter().enumerate() {
+            let pat_type = match arg {
+                syn::FnArg::Typed(pat_type) => pat_type,
+                syn::FnArg::Receiver(_) => continue,
+            };
+            let mut defaults = pat_type
+                .attrs
+                .iter()
+                .filter(|attr| attr.is_default_attribute());
+            if let Some(default) = defaults.next() {
+                if n == 0 && context.is_some() {
+                    bail_spanned!(
+                        default,
+                        "cannot have #[default] value for the context parameter"
+                    )
+                }
+                if default.parse_args::<syn::Expr>().is_err() {
+                    bail_spanned!(default, "expected a single expression as #[default] value")
+                }
+            }
+            if let Some(duplicate) = defaults.next() {
+                bail_spanned!(duplicate, "encountered duplicate #[default] value")
+            }
+        }
+        if let Some(context) = context {
+            let make_err = |error: &dyn ToTokens, help: String| {
                 format_err_spanned!(
                     error,
-                    "must have type of `&mut Self::Context` as first argument"
+                    "must have type of `&mut Self::Context` or `&Self::Context` as first argument; help: {help}"
                 )
                 .into_combine(format_err_spanned!(
                     context,
@@ -307,6 +484,7 @@ impl UnionFnState {
                 ))
                 .into_result()
             };
+            let context_str = context.to_token_stream().to_string();
             match item.sig.inputs.first() {
                 Some(arg) => match arg {
                     syn::FnArg::Receiver(receiver) => bail_spanned!(
@@ -314,12 +492,51 @@ impl UnionFnState {
                         "must not have a `self` receiver as first argument in #[union_fn] methods"
                     ),
                     syn::FnArg::Typed(pat_type) => {
-                        if *pat_type.ty != syn::parse_quote!(&mut Self::Context) {
-                            return make_err(pat_type);
+                        if *pat_type.ty != syn::parse_quote!(&mut Self::Context)
+                            && *pat_type.ty != syn::parse_quote!(&Self::Context)
+                        {
+                            let ty_str = pat_type.ty.to_token_stream().to_string();
+                            // The first argument is considered a malformed context parameter if
+                            // its type resembles the context and an ordinary parameter otherwise.
+                            let is_context_like = matches!(
+                                &*pat_type.ty,
+                                syn::Type::Reference(_) | syn::Type::Ptr(_)
+                            ) || ty_str.contains("Context")
+                                || ty_str == context_str;
+                            let help = match (&*pat_type.pat, is_context_like) {
+                                (syn::Pat::Ident(pat_ident), true) => {
+                                    let ctx_name = &pat_ident.ident;
+                                    format!(
+                                        "change the first argument to `{ctx_name}: &mut Self::Context` \
+                                         or `{ctx_name}: &Self::Context` with Context being `{context_str}`"
+                                    )
+                                }
+                                (_, true) => format!(
+                                    "change the first argument to `ctx: &mut Self::Context` \
+                                     or `ctx: &Self::Context` with Context being `{context_str}`"
+                                ),
+                                (_, false) => {
+                                    let pat_str = pat_type.pat.to_token_stream().to_string();
+                                    format!(
+                                        "insert `ctx: &mut Self::Context` or `ctx: &Self::Context` \
+                                         before `{pat_str}` with Context being `{context_str}`"
+                                    )
+                                }
+                            };
+                            return make_err(pat_type, help);
                         }
                     }
                 },
-                None => return make_err(&item.sig),
+                None => {
+                    return make_err(
+                        &item.sig,
+                        format!(
+                            "this method is missing a context argument entirely, \
+                             add `ctx: &mut Self::Context` or `ctx: &Self::Context` \
+                             with Context being `{context_str}`"
+                        ),
+                    )
+                }
             }
         }
         Ok(())
@@ -333,15 +550,226 @@ impl UnionFn {
     ///
     /// If the `item` is invalid or unsupported.
     pub fn new(args: TokenStream2, item: TokenStream2) -> Result<Self> {
-        if !args.is_empty() {
-            bail_spanned!(args, "cannot have macro arguments for #[union_fn]")
-        }
+        let config = UnionFnConfig::parse(args)?;
         let mut item = syn::parse2::<syn::ItemTrait>(item)?;
         Self::analyze_trait(&item)?;
-        let mut state = UnionFnState::default();
-        Self::sort_items(&mut item.items);
-        Self::analyze_items(&mut state, &item.items)?;
-        Ok(Self { item, state })
+        let declaration_order = item
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::TraitItem::Method(method) => Some(method.sig.ident.clone()),
+                _ => None,
+            })
+            .collect();
+        Self::sort_items(&mut item.items, config.stable_discriminants().is_some());
+        let mut state = UnionFnState::new(config);
+        Self::analyze_items(&mut state, &item)?;
+        let union_fn = Self {
+            item,
+            state,
+            declaration_order,
+        };
+        union_fn.analyze_config()?;
+        union_fn.analyze_named_args()?;
+        union_fn.analyze_input_defaults()?;
+        Ok(union_fn)
+    }
+
+    /// Analyzes the `#[union_fn]` configuration with respect to the analyzed trait.
+    ///
+    /// # Errors
+    ///
+    /// If the trait is incompatible with the configuration.
+    fn analyze_config(&self) -> Result<()> {
+        if let Some(no_opt) = self.config().no_opt() {
+            if self.config().no_enum().is_some() {
+                bail_spanned!(no_opt, "cannot use no_opt for #[union_fn] with no_enum")
+            }
+            if self.config().opt_name().is_some()
+                || self.config().args_name().is_some()
+                || self.config().delegate_name().is_some()
+            {
+                bail_spanned!(
+                    no_opt,
+                    "cannot use no_opt for #[union_fn] with opt_name, args_name or delegate_name"
+                )
+            }
+            if self.config().args_repr().is_some()
+                || self.config().args_align().is_some()
+                || self.config().max_args_size().is_some()
+                || self.config().warn_args_size().is_some()
+            {
+                bail_spanned!(
+                    no_opt,
+                    "cannot use no_opt for #[union_fn] with args_repr, args_align, max_args_size or warn_args_size"
+                )
+            }
+            if self.config().ffi().is_some() {
+                bail_spanned!(no_opt, "cannot use no_opt for #[union_fn] with ffi")
+            }
+        }
+        if let Some(bounds) = self.config().context_bounds() {
+            if self.state.get_context().is_none() {
+                bail_spanned!(
+                    bounds,
+                    "cannot use context_bounds for #[union_fn] trait without Context"
+                )
+            }
+        }
+        if let Some(asyncness) = self.asyncness() {
+            if self.config().no_opt().is_none() {
+                bail_spanned!(
+                    asyncness,
+                    "cannot use async methods for #[union_fn] without no_opt \
+                     since the call optimized type dispatches via function pointers"
+                )
+            }
+            if let Some(unsafety) = self.unsafety() {
+                bail_spanned!(
+                    unsafety,
+                    "cannot have async unsafe methods in #[union_fn] trait"
+                )
+            }
+        }
+        if self.config().no_opt().is_none() {
+            if let Some(ty) = self.non_copy_input() {
+                bail_spanned!(
+                    ty,
+                    "cannot use non-Copy parameter type for #[union_fn] without no_opt \
+                     since the call optimized type requires Copy parameters"
+                )
+            }
+        }
+        if let Some(non_exhaustive) = self.config().non_exhaustive() {
+            if self.config().no_enum().is_some() {
+                bail_spanned!(
+                    non_exhaustive,
+                    "cannot use non_exhaustive for #[union_fn] with no_enum"
+                )
+            }
+        }
+        if let Some(visitor) = self.config().visitor() {
+            if self.config().no_enum().is_some() {
+                bail_spanned!(visitor, "cannot use visitor for #[union_fn] with no_enum")
+            }
+        }
+        if let Some(binary) = self.config().binary() {
+            if self.config().no_enum().is_some() {
+                bail_spanned!(binary, "cannot use binary for #[union_fn] with no_enum")
+            }
+            let len_variants = self.methods().count();
+            if len_variants > 1 << 8 {
+                bail_spanned!(
+                    binary,
+                    "encountered too many methods for binary: \
+                     found {len_variants} but at most 256 are supported"
+                )
+            }
+        }
+        if let Some(serde) = self.config().serde() {
+            if self.config().no_enum().is_some() {
+                bail_spanned!(serde, "cannot use serde for #[union_fn] with no_enum")
+            }
+        }
+        if let Some(arbitrary) = self.config().arbitrary() {
+            if self.config().no_enum().is_some() {
+                bail_spanned!(
+                    arbitrary,
+                    "cannot use arbitrary for #[union_fn] with no_enum"
+                )
+            }
+        }
+        if let Some(repr) = self.config().enum_repr() {
+            if self.config().no_enum().is_some() {
+                bail_spanned!(repr, "cannot use enum_repr for #[union_fn] with no_enum")
+            }
+            let max_variants: Option<usize> = match repr.to_string().as_str() {
+                "u8" => Some(1 << 8),
+                "i8" => Some(1 << 7),
+                "u16" => Some(1 << 16),
+                "i16" => Some(1 << 15),
+                _ => None,
+            };
+            let len_variants = self.methods().count();
+            if let Some(max_variants) = max_variants {
+                if len_variants > max_variants {
+                    bail_spanned!(
+                        repr,
+                        "encountered too many methods for enum_repr = \"{repr}\": \
+                         found {len_variants} but at most {max_variants} are supported"
+                    )
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Analyzes the `#[named_args]` attributes of the `#[union_fn]` methods.
+    ///
+    /// # Errors
+    ///
+    /// - If a `#[named_args]` attribute has arguments or is applied to a method without parameters.
+    /// - If the identifier of a named arguments struct conflicts with a generated type.
+    fn analyze_named_args(&self) -> Result<()> {
+        let generated = [
+            self.ident().clone(),
+            self.ident_opt(),
+            self.ident_args(),
+            self.ident_delegate(),
+            self.ident_impls(),
+            self.ident_visitor(),
+        ];
+        let methods = self
+            .methods()
+            .filter_map(|method| method.named_args().map(|named_args| (method, named_args)));
+        for (method, named_args) in methods {
+            if !named_args.tokens.is_empty() {
+                bail_spanned!(named_args, "expected no arguments for #[named_args]")
+            }
+            if method.inputs(&self.state).next().is_none() {
+                bail_spanned!(
+                    named_args,
+                    "cannot use #[named_args] for #[union_fn] method without parameters"
+                )
+            }
+            let ident = method.ident_named_args();
+            if generated.contains(&ident) {
+                bail_spanned!(
+                    named_args,
+                    "the named arguments struct {ident} conflicts with a type generated by #[union_fn]"
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// Analyzes the `#[default]` parameter values of the `#[union_fn]` methods.
+    ///
+    /// # Errors
+    ///
+    /// - If a method with `#[default]` parameter values also uses `#[named_args]`.
+    /// - If the identifier of a default constructor conflicts with a method.
+    fn analyze_input_defaults(&self) -> Result<()> {
+        let methods = self
+            .methods()
+            .filter(|method| method.has_input_defaults(&self.state));
+        for method in methods {
+            if let Some(named_args) = method.named_args() {
+                bail_spanned!(
+                    named_args,
+                    "cannot use #[named_args] together with #[default] parameter values"
+                )
+            }
+            let ident = method.ident_default();
+            if let Some(conflict) = self.methods().find(|other| other.ident() == &ident) {
+                bail_spanned!(
+                    conflict.ident(),
+                    "the method {ident} conflicts with the default constructor of {}",
+                    method.ident()
+                )
+            }
+        }
+        Ok(())
     }
 
     /// Analyzes the trait definition without its trait items.
@@ -369,19 +797,19 @@ impl UnionFn {
     }
 
     /// Sort items in the way the macro analysis expects them to be sorted.
-    fn sort_items(items: &mut [syn::TraitItem]) {
+    ///
+    /// Methods are additionally sorted by name if `stable_discriminants` is set.
+    fn sort_items(items: &mut [syn::TraitItem], stable_discriminants: bool) {
         fn order_value(item: &syn::TraitItem) -> i32 {
             match item {
-                syn::TraitItem::Const(_)
-                | syn::TraitItem::Verbatim(_)
-                | syn::TraitItem::Macro(_) => {
+                syn::TraitItem::Verbatim(_) | syn::TraitItem::Macro(_) => {
                     // Unsupported items are sorted to the start
                     // so that they are filtered out early on in
                     // the analysis process.
                     0
                 }
-                syn::TraitItem::Type(_) => {
-                    // We need to process associated types before methods.
+                syn::TraitItem::Type(_) | syn::TraitItem::Const(_) => {
+                    // We need to process associated types and constants before methods.
                     1
                 }
                 syn::TraitItem::Method(_) => {
@@ -391,21 +819,139 @@ impl UnionFn {
                 _ => 0, // same as with other unsupported items
             }
         }
-        items.sort_by_key(order_value)
+        match stable_discriminants {
+            true => items.sort_by_cached_key(|item| {
+                let name = match item {
+                    syn::TraitItem::Method(method) => Some(method.sig.ident.to_string()),
+                    _ => None,
+                };
+                (order_value(item), name)
+            }),
+            false => items.sort_by_key(order_value),
+        }
     }
 
     /// Analyzes the trait items and updates the `state` respectively.
     ///
     /// # Errors
     ///
-    /// If unsupported or invalid items are encountered.
-    fn analyze_items(state: &mut UnionFnState, items: &[syn::TraitItem]) -> Result<()> {
-        items
+    /// - If unsupported or invalid items are encountered.
+    /// - If the trait does not define any methods.
+    fn analyze_items(state: &mut UnionFnState, item: &syn::ItemTrait) -> Result<()> {
+        // Analyze all items before bailing out in order to report all errors at once.
+        let error = item
+            .items
             .iter()
-            .try_for_each(|item| Self::analyze_item(state, item))?;
+            .filter_map(|item| Self::analyze_item(state, item).err())
+            .reduce(|error, another| error.into_combine(another));
+        if let Some(error) = error {
+            return error.into_result();
+        }
+        Self::analyze_variant_names(item)?;
+        Self::analyze_generated_names(state, item)?;
+        if !item
+            .items
+            .iter()
+            .any(|item| matches!(item, syn::TraitItem::Method(_)))
+        {
+            bail_spanned!(
+                item.ident,
+                "#[union_fn] trait must define at least one method"
+            )
+        }
         Ok(())
     }
 
+    /// Analyzes the enum variant names generated for the methods of the trait `item`.
+    ///
+    /// # Errors
+    ///
+    /// If multiple methods generate the same enum variant name, e.g. `foo_bar` and `FooBar`.
+    fn analyze_variant_names(item: &syn::ItemTrait) -> Result<()> {
+        let mut variants = HashMap::new();
+        let error = item
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::TraitItem::Method(method) => Some(&method.sig.ident),
+                _ => None,
+            })
+            .filter_map(|ident| {
+                let variant = ident.to_string().to_upper_camel_case();
+                let previous = variants.insert(variant.clone(), ident)?;
+                Some(
+                    format_err_spanned!(
+                        ident,
+                        "method `{ident}` generates the same enum variant `{variant}` as method `{previous}`"
+                    )
+                    .into_combine(format_err_spanned!(
+                        previous,
+                        "method `{previous}` generating enum variant `{variant}` defined here"
+                    )),
+                )
+            })
+            .reduce(|error, another| error.into_combine(another));
+        match error {
+            Some(error) => error.into_result(),
+            None => Ok(()),
+        }
+    }
+
+    /// Analyzes the names of the methods of the trait `item` for clashes with generated enum methods.
+    ///
+    /// # Errors
+    ///
+    /// - If a method has the same name as an inherent method generated for the enum, e.g. `discriminant`.
+    /// - If a method has the same name as an inherent method generated for another method, e.g. `is_foo` for `foo`.
+    fn analyze_generated_names(state: &UnionFnState, item: &syn::ItemTrait) -> Result<()> {
+        /// The inherent methods generated for the enum independently of the `#[union_fn]` methods.
+        const RESERVED_NAMES: &[&str] = &[
+            "discriminant",
+            "from_discriminant",
+            "variant_name",
+            "variants",
+            "zero_arg_variants",
+        ];
+        /// The prefixes and kinds of the inherent methods generated for the enum per `#[union_fn]` method.
+        const GENERATED_PREFIXES: &[(&str, &str)] = &[("is_", "predicate"), ("as_", "accessor")];
+        // The inherent methods of the enum are not generated without the enum.
+        if state.config().no_enum().is_some() {
+            return Ok(());
+        }
+        let idents = item
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::TraitItem::Method(method) => Some(&method.sig.ident),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let error = idents
+            .iter()
+            .filter_map(|ident| {
+                if RESERVED_NAMES.iter().any(|name| *ident == name) {
+                    return Some(format_err_spanned!(
+                        ident,
+                        "method `{ident}` clashes with the generated `{ident}` method of the #[union_fn] enum"
+                    ));
+                }
+                let name = ident.to_string();
+                GENERATED_PREFIXES.iter().find_map(|(prefix, kind)| {
+                    let base = name.strip_prefix(prefix)?;
+                    let method = idents.iter().find(|other| **other == base)?;
+                    Some(format_err_spanned!(
+                        ident,
+                        "method `{ident}` clashes with the generated `{ident}` {kind} of method `{method}`"
+                    ))
+                })
+            })
+            .reduce(|error, another| error.into_combine(another));
+        match error {
+            Some(error) => error.into_result(),
+            None => Ok(()),
+        }
+    }
+
     /// Analyzes the given trait `item` and updates the `state` respectively.
     ///
     /// # Errors
@@ -423,7 +969,7 @@ impl UnionFn {
         match item {
             syn::TraitItem::Method(item) => state.register_method(item),
             syn::TraitItem::Type(item) => state.register_type(item),
-            syn::TraitItem::Const(item) => unsupported!(item),
+            syn::TraitItem::Const(item) => state.register_const(item),
             syn::TraitItem::Macro(item) => unsupported!(item),
             syn::TraitItem::Verbatim(item) => unsupported!(item),
             unknown => bail_spanned!(unknown, "encountered unknown item in #[union_fn] trait"),
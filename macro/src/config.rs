@@ -0,0 +1,409 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Parser as _, punctuated::Punctuated, Result};
+
+/// The configuration of a `#[union_fn]` macro invocation given via its arguments.
+#[derive(Default)]
+pub struct UnionFnConfig {
+    /// The integer representation of the generated enum if any.
+    enum_repr: Option<syn::Ident>,
+    /// The representation of the generated args union if any.
+    args_repr: Option<syn::Ident>,
+    /// The alignment of the generated args union if any.
+    args_align: Option<syn::LitInt>,
+    /// The maximum size of the generated args union in bytes if any.
+    max_args_size: Option<syn::LitInt>,
+    /// The size of the generated args union in bytes above which a warning is emitted if any.
+    warn_args_size: Option<syn::LitInt>,
+    /// The maximum number of methods of the `#[union_fn]` trait if any.
+    max_variants: Option<syn::LitInt>,
+    /// Set if the generation of the enum is skipped.
+    no_enum: Option<syn::Path>,
+    /// Set if the generation of the call optimized type is skipped.
+    no_opt: Option<syn::Path>,
+    /// Set if the generated enum is `#[non_exhaustive]`.
+    non_exhaustive: Option<syn::Path>,
+    /// Set if a visitor trait for the generated enum is generated.
+    visitor: Option<syn::Path>,
+    /// Set if binary encoding and decoding for the generated enum is generated.
+    binary: Option<syn::Path>,
+    /// Set if `Serialize` and `Deserialize` are derived for the generated enum.
+    serde: Option<syn::Path>,
+    /// Set if `Arbitrary` is implemented for the generated enum.
+    arbitrary: Option<syn::Path>,
+    /// Set if the call optimized type has a C compatible layout and handler ABI.
+    ffi: Option<syn::Path>,
+    /// The trait bounds required for the context type if any.
+    context_bounds: Option<Punctuated<syn::TypeParamBound, syn::Token![+]>>,
+    /// Set if the discriminants are assigned in alphabetical order of the method names.
+    stable_discriminants: Option<syn::Path>,
+    /// The prefix of the identifiers of the generated internal types if any.
+    prefix: Option<syn::LitStr>,
+    /// The custom identifier of the call optimized type if any.
+    opt_name: Option<syn::Ident>,
+    /// The custom identifier of the args union if any.
+    args_name: Option<syn::Ident>,
+    /// The custom identifier of the delegate type if any.
+    delegate_name: Option<syn::Ident>,
+    /// The identifier of the module holding the internal types if any.
+    module: Option<syn::Ident>,
+}
+
+impl UnionFnConfig {
+    /// Parses the `#[union_fn]` macro arguments into a [`UnionFnConfig`].
+    ///
+    /// # Errors
+    ///
+    /// If the arguments are malformed, unsupported or specified multiple times.
+    pub fn parse(args: TokenStream2) -> Result<Self> {
+        let metas = Punctuated::<syn::NestedMeta, syn::Token![,]>::parse_terminated.parse2(args)?;
+        let mut config = Self::default();
+        for meta in &metas {
+            config.register_arg(meta)?;
+        }
+        Ok(config)
+    }
+
+    /// Registers a single `#[union_fn]` macro argument.
+    ///
+    /// # Errors
+    ///
+    /// If the argument is malformed, unsupported or has already been registered.
+    fn register_arg(&mut self, meta: &syn::NestedMeta) -> Result<()> {
+        let meta = match meta {
+            syn::NestedMeta::Meta(meta) => meta,
+            syn::NestedMeta::Lit(lit) => {
+                bail_spanned!(lit, "encountered unsupported argument for #[union_fn]")
+            }
+        };
+        let path = meta.path();
+        if path.is_ident("enum_repr") {
+            let repr = Self::expect_ident_str(meta)?;
+            const SUPPORTED: &[&str] = &[
+                "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+            ];
+            if !SUPPORTED.iter().any(|supported| repr == supported) {
+                bail_spanned!(
+                    meta,
+                    "encountered unsupported enum_repr for #[union_fn]; expected one of: {}",
+                    SUPPORTED.join(", ")
+                )
+            }
+            return Self::set_once(&mut self.enum_repr, repr, meta);
+        }
+        if path.is_ident("args_repr") {
+            let repr = Self::expect_ident_str(meta)?;
+            if repr != "C" {
+                bail_spanned!(
+                    meta,
+                    "encountered unsupported args_repr for #[union_fn]; expected: C"
+                )
+            }
+            return Self::set_once(&mut self.args_repr, repr, meta);
+        }
+        if path.is_ident("args_align") {
+            let align = Self::expect_int(meta)?;
+            let value = align.base10_parse::<u32>()?;
+            if !value.is_power_of_two() {
+                bail_spanned!(
+                    meta,
+                    "encountered invalid args_align for #[union_fn]; expected a power of two"
+                )
+            }
+            let align = syn::LitInt::new(&value.to_string(), align.span());
+            return Self::set_once(&mut self.args_align, align, meta);
+        }
+        if path.is_ident("max_args_size") {
+            let size = Self::expect_int(meta)?;
+            let value = size.base10_parse::<usize>()?;
+            let size = syn::LitInt::new(&format!("{value}usize"), size.span());
+            return Self::set_once(&mut self.max_args_size, size, meta);
+        }
+        if path.is_ident("warn_args_size") {
+            let size = Self::expect_int(meta)?;
+            let value = size.base10_parse::<usize>()?;
+            let size = syn::LitInt::new(&format!("{value}usize"), size.span());
+            return Self::set_once(&mut self.warn_args_size, size, meta);
+        }
+        if path.is_ident("max_variants") {
+            let count = Self::expect_int(meta)?;
+            let value = count.base10_parse::<usize>()?;
+            let count = syn::LitInt::new(&format!("{value}usize"), count.span());
+            return Self::set_once(&mut self.max_variants, count, meta);
+        }
+        if path.is_ident("prefix") {
+            let prefix = Self::expect_prefix_str(meta)?;
+            return Self::set_once(&mut self.prefix, prefix, meta);
+        }
+        if path.is_ident("opt_name") {
+            let name = Self::expect_ident_str(meta)?;
+            return Self::set_once(&mut self.opt_name, name, meta);
+        }
+        if path.is_ident("args_name") {
+            let name = Self::expect_ident_str(meta)?;
+            return Self::set_once(&mut self.args_name, name, meta);
+        }
+        if path.is_ident("delegate_name") {
+            let name = Self::expect_ident_str(meta)?;
+            return Self::set_once(&mut self.delegate_name, name, meta);
+        }
+        if path.is_ident("module") {
+            let name = Self::expect_ident_str(meta)?;
+            return Self::set_once(&mut self.module, name, meta);
+        }
+        if path.is_ident("no_enum") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.no_enum, flag.clone(), meta);
+        }
+        if path.is_ident("no_opt") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.no_opt, flag.clone(), meta);
+        }
+        if path.is_ident("non_exhaustive") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.non_exhaustive, flag.clone(), meta);
+        }
+        if path.is_ident("visitor") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.visitor, flag.clone(), meta);
+        }
+        if path.is_ident("binary") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.binary, flag.clone(), meta);
+        }
+        if path.is_ident("serde") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.serde, flag.clone(), meta);
+        }
+        if path.is_ident("arbitrary") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.arbitrary, flag.clone(), meta);
+        }
+        if path.is_ident("ffi") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.ffi, flag.clone(), meta);
+        }
+        if path.is_ident("context_bounds") {
+            let bounds = Self::expect_bounds_str(meta)?;
+            return Self::set_once(&mut self.context_bounds, bounds, meta);
+        }
+        if path.is_ident("stable_discriminants") {
+            let flag = Self::expect_flag(meta)?;
+            return Self::set_once(&mut self.stable_discriminants, flag.clone(), meta);
+        }
+        bail_spanned!(meta, "encountered unsupported argument for #[union_fn]")
+    }
+
+    /// Sets `value` to `new_value` or returns an error if it was already set.
+    fn set_once<T>(value: &mut Option<T>, new_value: T, meta: &syn::Meta) -> Result<()> {
+        if value.is_some() {
+            bail_spanned!(meta, "encountered duplicate argument for #[union_fn]")
+        }
+        *value = Some(new_value);
+        Ok(())
+    }
+
+    /// Returns the path of the `flag` argument.
+    ///
+    /// # Errors
+    ///
+    /// If the argument is not a plain `flag` without value.
+    fn expect_flag(meta: &syn::Meta) -> Result<&syn::Path> {
+        match meta {
+            syn::Meta::Path(path) => Ok(path),
+            _ => bail_spanned!(meta, "expected no value for #[union_fn] flag argument"),
+        }
+    }
+
+    /// Returns the identifier given as string literal value of the `key = "value"` argument.
+    ///
+    /// # Errors
+    ///
+    /// If the argument is not a `key = "value"` pair or `value` is not an identifier.
+    fn expect_ident_str(meta: &syn::Meta) -> Result<syn::Ident> {
+        match meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => lit_str.parse::<syn::Ident>().map_err(|_| {
+                format_err_spanned!(lit_str, "expected an identifier as string literal")
+            }),
+            _ => bail_spanned!(
+                meta,
+                "expected a string literal value for #[union_fn] argument"
+            ),
+        }
+    }
+
+    /// Returns the identifier prefix given as string literal value of the `key = "value"` argument.
+    ///
+    /// # Errors
+    ///
+    /// If the argument is not a `key = "value"` pair or `value` cannot prefix an identifier.
+    fn expect_prefix_str(meta: &syn::Meta) -> Result<syn::LitStr> {
+        match meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => {
+                if syn::parse_str::<syn::Ident>(&format!("{}Prefixed", lit_str.value())).is_err() {
+                    bail_spanned!(lit_str, "expected an identifier prefix as string literal")
+                }
+                Ok(lit_str.clone())
+            }
+            _ => bail_spanned!(
+                meta,
+                "expected a string literal value for #[union_fn] argument"
+            ),
+        }
+    }
+
+    /// Returns the trait bounds given as string literal value of the `key = "value"` argument.
+    ///
+    /// # Errors
+    ///
+    /// If the argument is not a `key = "value"` pair or `value` is not a list of trait bounds.
+    fn expect_bounds_str(
+        meta: &syn::Meta,
+    ) -> Result<Punctuated<syn::TypeParamBound, syn::Token![+]>> {
+        match meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => lit_str
+                .parse_with(Punctuated::parse_separated_nonempty)
+                .map_err(|_| {
+                    format_err_spanned!(lit_str, "expected trait bounds as string literal")
+                }),
+            _ => bail_spanned!(
+                meta,
+                "expected a string literal value for #[union_fn] argument"
+            ),
+        }
+    }
+
+    /// Returns the integer given as value of the `key = N` or `key = "N"` argument.
+    ///
+    /// # Errors
+    ///
+    /// If the argument is not a `key = value` pair or `value` is not an integer.
+    fn expect_int(meta: &syn::Meta) -> Result<syn::LitInt> {
+        match meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }) => Ok(lit_int.clone()),
+            syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => lit_str
+                .parse::<syn::LitInt>()
+                .map_err(|_| format_err_spanned!(lit_str, "expected an integer as string literal")),
+            _ => bail_spanned!(meta, "expected an integer value for #[union_fn] argument"),
+        }
+    }
+
+    /// Returns the integer representation of the generated enum if any.
+    pub fn enum_repr(&self) -> Option<&syn::Ident> {
+        self.enum_repr.as_ref()
+    }
+
+    /// Returns the representation of the generated args union if any.
+    pub fn args_repr(&self) -> Option<&syn::Ident> {
+        self.args_repr.as_ref()
+    }
+
+    /// Returns the alignment of the generated args union if any.
+    pub fn args_align(&self) -> Option<&syn::LitInt> {
+        self.args_align.as_ref()
+    }
+
+    /// Returns the maximum size of the generated args union in bytes if any.
+    pub fn max_args_size(&self) -> Option<&syn::LitInt> {
+        self.max_args_size.as_ref()
+    }
+
+    /// Returns the size of the generated args union in bytes above which a warning is emitted if any.
+    pub fn warn_args_size(&self) -> Option<&syn::LitInt> {
+        self.warn_args_size.as_ref()
+    }
+
+    /// Returns the maximum number of methods of the `#[union_fn]` trait if any.
+    pub fn max_variants(&self) -> Option<&syn::LitInt> {
+        self.max_variants.as_ref()
+    }
+
+    /// Returns the `no_enum` flag if the generation of the enum is skipped.
+    pub fn no_enum(&self) -> Option<&syn::Path> {
+        self.no_enum.as_ref()
+    }
+
+    /// Returns the `no_opt` flag if the generation of the call optimized type is skipped.
+    pub fn no_opt(&self) -> Option<&syn::Path> {
+        self.no_opt.as_ref()
+    }
+
+    /// Returns the `non_exhaustive` flag if the generated enum is `#[non_exhaustive]`.
+    pub fn non_exhaustive(&self) -> Option<&syn::Path> {
+        self.non_exhaustive.as_ref()
+    }
+
+    /// Returns the `visitor` flag if a visitor trait for the generated enum is generated.
+    pub fn visitor(&self) -> Option<&syn::Path> {
+        self.visitor.as_ref()
+    }
+
+    /// Returns the `binary` flag if binary encoding and decoding for the generated enum is generated.
+    pub fn binary(&self) -> Option<&syn::Path> {
+        self.binary.as_ref()
+    }
+
+    /// Returns the `serde` flag if `Serialize` and `Deserialize` are derived for the generated enum.
+    pub fn serde(&self) -> Option<&syn::Path> {
+        self.serde.as_ref()
+    }
+
+    /// Returns the `arbitrary` flag if `Arbitrary` is implemented for the generated enum.
+    pub fn arbitrary(&self) -> Option<&syn::Path> {
+        self.arbitrary.as_ref()
+    }
+
+    /// Returns the `ffi` flag if the call optimized type has a C compatible layout and handler ABI.
+    pub fn ffi(&self) -> Option<&syn::Path> {
+        self.ffi.as_ref()
+    }
+
+    /// Returns the trait bounds required for the context type if any.
+    pub fn context_bounds(&self) -> Option<&Punctuated<syn::TypeParamBound, syn::Token![+]>> {
+        self.context_bounds.as_ref()
+    }
+
+    /// Returns the `stable_discriminants` flag if the discriminants are assigned in alphabetical order of the method names.
+    pub fn stable_discriminants(&self) -> Option<&syn::Path> {
+        self.stable_discriminants.as_ref()
+    }
+
+    /// Returns the prefix of the identifiers of the generated internal types if any.
+    pub fn prefix(&self) -> Option<&syn::LitStr> {
+        self.prefix.as_ref()
+    }
+
+    /// Returns the custom identifier of the call optimized type if any.
+    pub fn opt_name(&self) -> Option<&syn::Ident> {
+        self.opt_name.as_ref()
+    }
+
+    /// Returns the custom identifier of the args union if any.
+    pub fn args_name(&self) -> Option<&syn::Ident> {
+        self.args_name.as_ref()
+    }
+
+    /// Returns the custom identifier of the delegate type if any.
+    pub fn delegate_name(&self) -> Option<&syn::Ident> {
+        self.delegate_name.as_ref()
+    }
+
+    /// Returns the identifier of the module holding the internal types if any.
+    pub fn module(&self) -> Option<&syn::Ident> {
+        self.module.as_ref()
+    }
+}
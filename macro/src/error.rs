@@ -44,3 +44,27 @@ macro_rules! format_err {
         )
     }
 }
+
+/// Emits a non-fatal warning at the span of `tokens`.
+///
+/// # Note
+///
+/// Requires `proc_macro::Diagnostic` which is only available on nightly Rust
+/// with `--cfg proc_macro_diagnostic` and otherwise does nothing.
+macro_rules! warn_spanned {
+    ($tokens:expr, $($msg:tt)*) => {{
+        #[cfg(proc_macro_diagnostic)]
+        {
+            ::proc_macro::Diagnostic::spanned(
+                <_ as ::syn::spanned::Spanned>::span(&$tokens).unwrap(),
+                ::proc_macro::Level::Warning,
+                format!($($msg)*),
+            )
+            .emit();
+        }
+        #[cfg(not(proc_macro_diagnostic))]
+        {
+            let _ = (&$tokens, format_args!($($msg)*));
+        }
+    }};
+}
@@ -1,7 +1,11 @@
-use crate::utils::IdentExt as _;
-use crate::{utils::make_tuple_type, UnionFn};
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote_spanned;
+use crate::utils::{AttributeExt as _, IdentExt as _};
+use crate::{
+    method::UnionFnMethod,
+    utils::{estimate_tuple_layout, make_tuple_type, make_unsafe_block, round_up},
+    UnionFn,
+};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote_spanned, ToTokens as _};
 use syn::spanned::Spanned as _;
 
 impl UnionFn {
@@ -9,23 +13,431 @@ impl UnionFn {
     pub fn expand(&self) -> TokenStream2 {
         let span = self.item.span();
         let reflect = self.expand_reflection();
+        let named_args = self.expand_named_args();
+        let variant_count_check = self.expand_union_fn_variant_count_check();
+        let variant_tables = self.expand_variant_tables();
+        let discriminants = self.expand_discriminants();
+        if self.config().no_opt().is_some() {
+            let enum_type = self.expand_union_fn_enum();
+            let internals = self.expand_internals(quote_spanned!(span=>
+                #reflect
+                #variant_count_check
+            ));
+            return quote_spanned!(span=>
+                #enum_type
+                #named_args
+                #variant_tables
+                #discriminants
+                #internals
+            );
+        }
         let args_type = self.expand_union_fn_args();
         let delegate_type = self.expand_union_fn_delegate();
         let impls_type = self.expand_union_fn_impls();
+        let opt_struct = self.expand_union_fn_opt_struct();
         let opt_type = self.expand_union_fn_opt();
-        let enum_type = self.expand_union_fn_enum();
+        let args_size_check = self.expand_union_fn_args_size_check();
+        let args_size_warning = self.expand_union_fn_args_size_warning();
+        let ffi_check = self.expand_union_fn_ffi_check();
+        let call_counts = self.expand_union_fn_call_counts();
+        let context_alias = self.expand_context_alias();
+        // The call optimized type takes the place of the enum if its generation is skipped
+        // since it otherwise could not be named from outside of the `const` block.
+        let (public_type, opt_struct) = match self.config().no_enum().is_some() {
+            true => (opt_struct, None),
+            false => (self.expand_union_fn_enum(), Some(opt_struct)),
+        };
+        let internals = self.expand_internals(quote_spanned!(span=>
+            #context_alias
+            #opt_struct
+            #opt_type
+            #args_type
+            #reflect
+            #delegate_type
+            #impls_type
+            #args_size_check
+            #args_size_warning
+            #ffi_check
+            #call_counts
+            #variant_count_check
+        ));
+        quote_spanned!(span=>
+            #public_type
+            #named_args
+            #variant_tables
+            #discriminants
+            #internals
+        )
+    }
+
+    /// Expands the `static` arrays of all method names and argument sizes indexable by their discriminant.
+    fn expand_variant_tables(&self) -> TokenStream2 {
+        let span = self.span();
+        let public_type = match self.config().no_enum().is_some() {
+            true => self.ident_opt(),
+            false => self.ident().clone(),
+        };
+        let ident_variant_names = self.ident_variant_names();
+        let ident_variant_arg_sizes = self.ident_variant_arg_sizes();
+        let names_docs = format!(
+            "The names of all `#[union_fn]` methods of [`{public_type}`] indexable by their discriminant."
+        );
+        let arg_sizes_docs = format!(
+            "The sizes of the arguments of all `#[union_fn]` methods of [`{public_type}`] in bytes indexable by their discriminant."
+        );
+        let names = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let name = method.display_name();
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #name,
+            )
+        });
+        let arg_sizes = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let input_types = method.input_types(&self.state);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                ::core::mem::size_of::<( #( #input_types, )* )>(),
+            )
+        });
+        quote_spanned!(span=>
+            #[doc = #names_docs]
+            pub static #ident_variant_names: [&::core::primitive::str; #public_type::VARIANT_COUNT] = [
+                #( #names )*
+            ];
+
+            #[doc = #arg_sizes_docs]
+            pub static #ident_variant_arg_sizes: [::core::primitive::usize; #public_type::VARIANT_COUNT] = [
+                #( #arg_sizes )*
+            ];
+        )
+    }
+
+    /// Expands the hidden module assigning the discriminants of all `#[union_fn]` methods.
+    ///
+    /// # Note
+    ///
+    /// The discriminants are computed by the compiler from a fieldless enum carrying the
+    /// `#[cfg]` attributes of the methods. This keeps them contiguous and in sync with the
+    /// variant tables even if some of the methods are configured out.
+    fn expand_discriminants(&self) -> TokenStream2 {
+        let span = self.span();
+        let ident_discriminants = self.ident_discriminants();
+        let variants = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method.ident().to_upper_camel_case();
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #variant_ident,
+            )
+        });
+        let consts = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method.ident().to_upper_camel_case();
+            let const_ident = method.ident().to_shouty_snake_case();
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                pub const #const_ident: ::core::primitive::usize =
+                    Discriminants::#variant_ident as ::core::primitive::usize;
+            )
+        });
         quote_spanned!(span=>
-            #enum_type
+            #[doc(hidden)]
+            #[allow(dead_code, non_snake_case)]
+            mod #ident_discriminants {
+                enum Discriminants {
+                    #( #variants )*
+                }
+
+                #( #consts )*
+            }
+        )
+    }
+
+    /// Expands the discriminant of the `method` as computed by the compiler.
+    fn expand_discriminant(&self, method: &UnionFnMethod) -> TokenStream2 {
+        let ident_discriminants = self.ident_discriminants();
+        let const_ident = method.ident().to_shouty_snake_case();
+        quote_spanned!(method.span()=> #ident_discriminants::#const_ident)
+    }
+
+    /// Expands the compile time assertion on the size of the args union.
+    ///
+    /// Returns `None` if the `max_args_size` macro argument is missing.
+    fn expand_union_fn_args_size_check(&self) -> Option<TokenStream2> {
+        let max_args_size = self.config().max_args_size()?;
+        let span = max_args_size.span();
+        let ident_opt = self.ident_opt();
+        let message = format!(
+            "the arguments of #[union_fn] trait {} exceed max_args_size of {} bytes; \
+             consider passing large method parameters indirectly",
+            self.ident(),
+            max_args_size.base10_digits(),
+        );
+        Some(quote_spanned!(span=>
+            const _: () = ::core::assert!(
+                <#ident_opt as ::union_fn::UnionFn>::ARGS_SIZE <= #max_args_size,
+                #message,
+            );
+        ))
+    }
+
+    /// Expands the compile time warning if the args union exceeds the `warn_args_size` bytes.
+    ///
+    /// # Note
+    ///
+    /// Uses 64 bytes if the `warn_args_size` macro argument is missing.
+    /// Since proc. macros cannot emit warnings on stable Rust this calls a
+    /// `#[deprecated]` function that is only selected if the size is exceeded.
+    ///
+    /// If `proc_macro::Diagnostic` is available and the size of the args union
+    /// can be estimated the warning is emitted directly by the macro instead.
+    fn expand_union_fn_args_size_warning(&self) -> TokenStream2 {
+        let warn_args_size = self
+            .config()
+            .warn_args_size()
+            .cloned()
+            .unwrap_or_else(|| syn::LitInt::new("64usize", self.ident().span()));
+        let span = warn_args_size.span();
+        let ident_opt = self.ident_opt();
+        let note = format!(
+            "the arguments of #[union_fn] trait {} exceed warn_args_size of {} bytes; \
+             consider passing large method parameters indirectly or raising warn_args_size",
+            self.ident(),
+            warn_args_size.base10_digits(),
+        );
+        if cfg!(proc_macro_diagnostic) {
+            if let Some(size) = self.estimate_args_size() {
+                if warn_args_size
+                    .base10_parse::<usize>()
+                    .is_ok_and(|warn_args_size| size > warn_args_size)
+                {
+                    warn_spanned!(self.ident(), "{note}");
+                }
+                return TokenStream2::new();
+            }
+        }
+        quote_spanned!(span=>
+            #[allow(clippy::absurd_extreme_comparisons)]
             const _: () = {
-                #opt_type
-                #args_type
-                #reflect
-                #delegate_type
-                #impls_type
+                struct ArgsSizeCheck<const EXCEEDED: ::core::primitive::bool>;
+                #[allow(dead_code)]
+                impl ArgsSizeCheck<false> {
+                    const fn check() {}
+                }
+                #[allow(dead_code)]
+                impl ArgsSizeCheck<true> {
+                    #[deprecated(note = #note)]
+                    const fn check() {}
+                }
+                ArgsSizeCheck::<{ <#ident_opt as ::union_fn::UnionFn>::ARGS_SIZE > #warn_args_size }>::check()
             };
         )
     }
 
+    /// Expands the compile time assertion on the number of methods.
+    ///
+    /// Returns `None` if the `max_variants` macro argument is missing.
+    fn expand_union_fn_variant_count_check(&self) -> Option<TokenStream2> {
+        let max_variants = self.config().max_variants()?;
+        let span = max_variants.span();
+        let public_type = match self.config().no_enum().is_some() {
+            true => self.ident_opt(),
+            false => self.ident().clone(),
+        };
+        let message = format!(
+            "the #[union_fn] trait {} has more methods than max_variants of {}",
+            self.ident(),
+            max_variants.base10_digits(),
+        );
+        Some(quote_spanned!(span=>
+            const _: () = ::core::assert!(
+                #public_type::VARIANT_COUNT <= #max_variants,
+                #message,
+            );
+        ))
+    }
+
+    /// Expands the `static` call counters of all methods and the `call_counts` method.
+    ///
+    /// Returns `None` unless the `call_counts` crate feature is enabled.
+    ///
+    /// # Note
+    ///
+    /// The counters are incremented by the delegates of the call optimized type
+    /// and thus only count calls dispatched through the call optimized type.
+    fn expand_union_fn_call_counts(&self) -> Option<TokenStream2> {
+        if !cfg!(feature = "call_counts") {
+            return None;
+        }
+        let span = self.span();
+        let public_type = match self.config().no_enum().is_some() {
+            true => self.ident_opt(),
+            false => self.ident().clone(),
+        };
+        let counters = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let ident_call_count = self.ident_call_count(&method);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                static #ident_call_count: ::core::sync::atomic::AtomicU64 =
+                    ::core::sync::atomic::AtomicU64::new(0);
+            )
+        });
+        let counts = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let name = method.display_name();
+            let ident_call_count = self.ident_call_count(&method);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                (#name, #ident_call_count.load(::core::sync::atomic::Ordering::Relaxed)),
+            )
+        });
+        Some(quote_spanned!(span=>
+            #( #counters )*
+
+            impl #public_type {
+                /// Returns the number of calls per `#[union_fn]` method ordered by their discriminants.
+                ///
+                /// Only calls dispatched through the call optimized type are counted.
+                pub fn call_counts() -> [(&'static ::core::primitive::str, ::core::primitive::u64); Self::VARIANT_COUNT] {
+                    [
+                        #( #counts )*
+                    ]
+                }
+            }
+        ))
+    }
+
+    /// Expands the compile time check that the context and all parameter types are FFI-safe.
+    ///
+    /// Returns `None` if the `ffi` macro argument is missing.
+    ///
+    /// # Note
+    ///
+    /// Wraps the types into `#[repr(C)]` structs passed to an `extern "C" fn` so that
+    /// the `improper_ctypes_definitions` lint rejects types without a C compatible layout.
+    fn expand_union_fn_ffi_check(&self) -> Option<TokenStream2> {
+        let ffi = self.config().ffi()?;
+        let span = ffi.span();
+        let ctx_check = self.state.get_context().map(|context| {
+            quote_spanned!(span=>
+                #[repr(C)]
+                struct FfiContext { _0: #context }
+            )
+        });
+        let ctx_param = self
+            .state
+            .get_context()
+            .map(|_| quote_spanned!(span=> _: FfiContext,));
+        let methods = self
+            .methods()
+            .filter(|method| method.inputs(&self.state).next().is_some())
+            .collect::<Vec<_>>();
+        let arg_checks = methods.iter().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let method_cfgs = method.cfg_attrs();
+            let input_types = method.input_types(&self.state);
+            let fields = (0..input_types.len()).map(|n| format_ident!("_{n}"));
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #[repr(C)]
+                struct #method_ident { #( #fields: #input_types ),* }
+            )
+        });
+        let arg_params = methods.iter().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let method_cfgs = method.cfg_attrs();
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                _: #method_ident,
+            )
+        });
+        Some(quote_spanned!(span=>
+            #[allow(dead_code, non_camel_case_types)]
+            const _: () = {
+                #ctx_check
+                #( #arg_checks )*
+
+                #[deny(improper_ctypes_definitions)]
+                #[allow(clippy::too_many_arguments)]
+                extern "C" fn ffi_check(#ctx_param #( #arg_params )*) {}
+            };
+        ))
+    }
+
+    /// Expands the visibility of internal items that are accessed by the user facing types.
+    ///
+    /// # Note
+    ///
+    /// Returns `pub(super)` if the internal items are put into a module
+    /// since otherwise the user facing types could no longer access them.
+    fn expand_internal_vis(&self, span: Span) -> Option<TokenStream2> {
+        self.config()
+            .module()
+            .map(|_| quote_spanned!(span=> pub(super)))
+    }
+
+    /// Expands the `__Context` type alias for the context of the call optimized `#[union_fn]` type.
+    ///
+    /// Returns `None` if the `#[union_fn]` trait has no context.
+    ///
+    /// # Note
+    ///
+    /// The alias is only available to the internal items and keeps their
+    /// signatures readable, e.g. when inspecting the expansion via `cargo expand`.
+    fn expand_context_alias(&self) -> Option<TokenStream2> {
+        self.state.get_context()?;
+        let span = self.span();
+        let ident_opt = self.ident_opt();
+        let call_trait = self.expand_call_trait(span);
+        Some(quote_spanned!(span=>
+            type __Context = <#ident_opt as #call_trait>::Context;
+        ))
+    }
+
+    /// Wraps the internal `items` of the `#[union_fn]` expansion.
+    ///
+    /// # Note
+    ///
+    /// The internal items are hidden in an anonymous `const` block by default
+    /// or put into a public module if the `module` argument has been provided.
+    fn expand_internals(&self, items: TokenStream2) -> TokenStream2 {
+        let span = self.span();
+        match self.config().module() {
+            Some(module) => {
+                let trait_ident = self.ident();
+                let module_docs =
+                    format!("Internal types generated by `#[union_fn]` for `{trait_ident}`.");
+                quote_spanned!(span=>
+                    #[doc = #module_docs]
+                    pub mod #module {
+                        #[allow(unused_imports)]
+                        use super::*;
+
+                        #items
+                    }
+                )
+            }
+            None => {
+                quote_spanned!(span=>
+                    const _: () = {
+                        #items
+                    };
+                )
+            }
+        }
+    }
+
     /// Exapnds the code to implement the base `UnionFn` trait.
     fn expand_reflection(&self) -> TokenStream2 {
         let trait_span = self.span();
@@ -33,16 +445,30 @@ impl UnionFn {
         let ident_opt = self.ident_opt();
         let ident_args = self.ident_args();
         let output = self.output_type();
+        if self.config().no_opt().is_some() {
+            // There are no packed arguments without the call optimized type.
+            return quote_spanned!(trait_span=>
+                impl ::union_fn::UnionFn for #trait_ident {
+                    type Output = #output;
+                    type Args = ();
+                }
+            );
+        }
+        let enum_reflection = self.config().no_enum().is_none().then(|| {
+            quote_spanned!(trait_span=>
+                impl ::union_fn::UnionFn for #trait_ident {
+                    type Output = #output;
+                    type Args = #ident_args;
+                }
+            )
+        });
         quote_spanned!(trait_span=>
             impl ::union_fn::UnionFn for #ident_opt {
                 type Output = #output;
                 type Args = #ident_args;
             }
 
-            impl ::union_fn::UnionFn for #trait_ident {
-                type Output = #output;
-                type Args = #ident_args;
-            }
+            #enum_reflection
         )
     }
 
@@ -51,156 +477,1021 @@ impl UnionFn {
         let trait_span = self.span();
         let trait_ident = self.ident();
         let impls_docs = format!("Implements all methods of the [`{trait_ident}`] type.");
+        let ident_opt = self.ident_opt();
         let ident_impls = self.ident_impls();
         let impls = self.methods().map(|method| {
             let method_span = method.span();
+            let internal_vis = self.expand_internal_vis(method_span);
+            let constness = self.constness();
+            let unsafety = self.unsafety();
+            let abi = self.abi();
             let method_ident = method.ident();
-            let method_attrs = method.attrs();
+            let method_attrs = method.internal_attrs();
             let impl_block = method.impl_block();
+            let ctx_mutability = method.context_mutability(&self.state);
             let ctx_param = method
                 .context(&self.state)
                 .map(|ctx| {
                     quote_spanned!(
-                        method_span=> #ctx: &mut <#trait_ident as ::union_fn::CallWithContext>::Context,
+                        method_span=> #ctx: & #ctx_mutability __Context,
                     )
                 });
-            let params = method.inputs(&self.state);
+            let params = method.impl_inputs(&self.state);
             quote_spanned!(method_span=>
                 #( #method_attrs )*
-                fn #method_ident( #ctx_param #( #params ),* ) -> <#trait_ident as ::union_fn::UnionFn>::Output #impl_block
+                #internal_vis #constness #unsafety #abi fn #method_ident( #ctx_param #( #params ),* ) -> <#ident_opt as ::union_fn::UnionFn>::Output #impl_block
+            )
+        });
+        let consts = self.consts().map(|item| {
+            let const_span = item.span();
+            let const_cfgs = item.attrs.iter().filter(|attr| attr.is_cfg_attribute());
+            let const_ident = &item.ident;
+            let const_type = &item.ty;
+            let public_type = match self.config().no_enum().is_some() {
+                true => &ident_opt,
+                false => trait_ident,
+            };
+            quote_spanned!(const_span=>
+                #( #const_cfgs )*
+                #[allow(dead_code)]
+                const #const_ident: #const_type = <#public_type>::#const_ident;
             )
         });
         quote_spanned!(trait_span=>
             #[doc = #impls_docs]
+            #[doc(hidden)]
             pub enum #ident_impls {}
 
             impl #ident_impls {
+                #( #consts )*
                 #( #impls )*
             }
         )
     }
 
+    /// Expands the `VARIANT_COUNT` constant of the user facing type.
+    fn expand_variant_count(&self) -> TokenStream2 {
+        let span = self.span();
+        let variants = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                (),
+            )
+        });
+        quote_spanned!(span=>
+            /// The number of `#[union_fn]` methods without those disabled via `#[cfg]`.
+            pub const VARIANT_COUNT: ::core::primitive::usize = [ #( #variants )* ].len();
+        )
+    }
+
+    /// Expands the associated constants of the `#[union_fn]` trait for the user facing type.
+    fn expand_consts(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        self.consts().map(|item| {
+            let const_span = item.span();
+            let const_attrs = &item.attrs;
+            let const_ident = &item.ident;
+            let const_type = &item.ty;
+            let (_, const_value) = item
+                .default
+                .as_ref()
+                .expect("associated consts have a default value after analysis");
+            quote_spanned!(const_span=>
+                #( #const_attrs )*
+                pub const #const_ident: #const_type = #const_value;
+            )
+        })
+    }
+
     /// Expand hidden delegators from `UnionFnArgs` to actual function parameters and implementations.
     fn expand_union_fn_delegate(&self) -> TokenStream2 {
         let trait_span = self.span();
         let trait_ident = self.ident();
         let delegate_docs = format!("Decodes and delegates packed arguments to the implementation of [`{trait_ident}`] methods.");
+        let ident_opt = self.ident_opt();
+        let ident_impls = self.ident_impls();
         let ident_delegate = self.ident_delegate();
         let delegates = self.methods().map(|method| {
             let method_span = method.span();
+            let internal_vis = self.expand_internal_vis(method_span);
+            // Delegates cannot be `const fn` if they increment their call counter.
+            let constness = self.constness().filter(|_| !cfg!(feature = "call_counts"));
+            let count_call = cfg!(feature = "call_counts").then(|| {
+                let ident_call_count = self.ident_call_count(&method);
+                quote_spanned!(method_span=>
+                    #ident_call_count.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+                )
+            });
+            let unsafety = self.unsafety();
+            let handler_unsafety = self.handler_unsafety();
+            let handler_abi = self.handler_abi();
             let method_ident = method.ident();
-            let method_attrs = method.attrs();
+            let method_attrs = method.internal_attrs();
             let ctx_ident = method
                 .context(&self.state)
                 .map(|ctx| quote_spanned!(method_span=> #ctx,));
-            let ctx_param = method
-                .context(&self.state)
-                .map(|ctx| {
-                    quote_spanned!(
-                        method_span=> #ctx: &mut <#trait_ident as ::union_fn::CallWithContext>::Context,
+            // Methods marked with `#[no_context]` still receive the unused context
+            // in their delegate since all handlers must have the same signature.
+            let ctx_pat = match method.context(&self.state) {
+                Some(ctx) => Some(ctx.to_token_stream()),
+                None => self
+                    .state
+                    .get_context()
+                    .map(|_| quote_spanned!(method_span=> _ctx)),
+            };
+            let (ctx_param, args_param, deref_params) = match self.config().ffi() {
+                Some(_) => {
+                    let ctx_param = ctx_pat.map(|ctx| {
+                        quote_spanned!(method_span=> #ctx: *mut __Context,)
+                    });
+                    let deref_ctx = method.context(&self.state).map(|ctx| {
+                        quote_spanned!(method_span=> let #ctx = unsafe { &mut *#ctx };)
+                    });
+                    (
+                        ctx_param,
+                        quote_spanned!(method_span=> args: *const <#ident_opt as ::union_fn::UnionFn>::Args),
+                        Some(quote_spanned!(method_span=>
+                            #deref_ctx
+                            let args = unsafe { &*args };
+                        )),
                     )
-                });
+                }
+                None => {
+                    let ctx_param = ctx_pat.map(|ctx| {
+                        quote_spanned!(method_span=> #ctx: &mut __Context,)
+                    });
+                    (
+                        ctx_param,
+                        quote_spanned!(method_span=> args: &<#ident_opt as ::union_fn::UnionFn>::Args),
+                        None,
+                    )
+                }
+            };
+            let bindings = method.input_bindings(&self.state);
+            let tuple_bindings = make_tuple_type(method_span, &bindings);
+            let (discriminant_param, discriminant_check) = match cfg!(feature = "checked_dispatch") {
+                true => {
+                    let message = format!(
+                        "encountered mismatching discriminant upon dispatch of `{method_ident}`"
+                    );
+                    let expected = self.expand_discriminant(&method);
+                    (
+                        Some(quote_spanned!(method_span=> , discriminant: ::core::primitive::usize)),
+                        Some(quote_spanned!(method_span=> ::core::assert!(discriminant == #expected, #message);)),
+                    )
+                }
+                false => (None, None),
+            };
+            let impls_call = make_unsafe_block(
+                unsafety,
+                quote_spanned!(method_span=>
+                    #ident_impls::#method_ident( #ctx_ident #( #bindings ),* )
+                ),
+            );
+            quote_spanned!(method_span=>
+                #( #method_attrs )*
+                #internal_vis #constness #handler_unsafety #handler_abi fn #method_ident( #ctx_param #args_param #discriminant_param )
+                    -> <#ident_opt as ::union_fn::UnionFn>::Output
+                {
+                    #discriminant_check
+                    #count_call
+                    #deref_params
+                    let #tuple_bindings = unsafe { args.#method_ident };
+                    #impls_call
+                }
+            )
+        });
+        quote_spanned!(trait_span=>
+            #[doc = #delegate_docs]
+            #[doc(hidden)]
+            pub enum #ident_delegate {}
+
+            impl #ident_delegate {
+                #( #delegates )*
+            }
+        )
+    }
+
+    /// Expands the struct definition of the call optimized `#[union_fn]` type.
+    ///
+    /// # Note
+    ///
+    /// The call optimized type inherits the attributes of the trait if the generation of
+    /// the enum is skipped since it then is the user facing `#[union_fn]` type.
+    fn expand_union_fn_opt_struct(&self) -> TokenStream2 {
+        let span = self.span();
+        let trait_ident = self.ident();
+        let ident_opt = self.ident_opt();
+        let opt_attrs = match self.config().no_enum().is_some() {
+            true => {
+                let attrs = self.attrs();
+                quote_spanned!(span=> #( #attrs )*)
+            }
+            false => {
+                let opt_docs = format!("Call optimized structure of the [`{trait_ident}`] type.");
+                let link_docs = format!(
+                    "Optimized call representation for [`{trait_ident}`]; \
+                     construct via [`{trait_ident}::into_opt`](union_fn::IntoOpt::into_opt)."
+                );
+                quote_spanned!(span=>
+                    #[doc = #opt_docs]
+                    #[doc = ""]
+                    #[doc = #link_docs]
+                )
+            }
+        };
+        let discriminant_field = cfg!(feature = "checked_dispatch")
+            .then(|| quote_spanned!(span=> discriminant: ::core::primitive::usize,));
+        let location_field = cfg!(feature = "source_locations").then(|| {
+            quote_spanned!(span=>
+                location: &'static ::core::panic::Location<'static>,
+            )
+        });
+        let repr = self
+            .config()
+            .ffi()
+            .map(|_| quote_spanned!(span=> #[repr(C)]));
+        let handler_type = self.expand_handler_type();
+        quote_spanned!(span=>
+            #opt_attrs
+            #[derive(::core::marker::Copy, ::core::clone::Clone)]
+            #repr
+            pub struct #ident_opt {
+                handler: #handler_type,
+                args: <#ident_opt as ::union_fn::UnionFn>::Args,
+                #discriminant_field
+                #location_field
+            }
+        )
+    }
+
+    /// Expands the function pointer type of the handler stored in the call optimized `#[union_fn]` type.
+    fn expand_handler_type(&self) -> TokenStream2 {
+        let span = self.span();
+        let ident_opt = self.ident_opt();
+        let discriminant_param = cfg!(feature = "checked_dispatch")
+            .then(|| quote_spanned!(span=> , ::core::primitive::usize));
+        let unsafety = self.handler_unsafety();
+        let abi = self.handler_abi();
+        let call_trait = self.expand_call_trait(span);
+        let (ctx, args) = match self.config().ffi() {
+            Some(_) => (
+                self.state.get_context().map(|_| {
+                    quote_spanned!(span=>
+                        ctx: *mut <#ident_opt as #call_trait>::Context,
+                    )
+                }),
+                quote_spanned!(span=> *const <#ident_opt as ::union_fn::UnionFn>::Args),
+            ),
+            None => (
+                self.state.get_context().map(|_| {
+                    quote_spanned!(span=>
+                        ctx: &mut <#ident_opt as #call_trait>::Context,
+                    )
+                }),
+                quote_spanned!(span=> &<#ident_opt as ::union_fn::UnionFn>::Args),
+            ),
+        };
+        quote_spanned!(span=>
+            #unsafety #abi fn(#ctx #args #discriminant_param) -> <#ident_opt as ::union_fn::UnionFn>::Output
+        )
+    }
+
+    /// Expand the impls of the call optimized `#[union_fn]` type.
+    fn expand_union_fn_opt(&self) -> TokenStream2 {
+        let span = self.span();
+        let call_impl = self.expand_call_impl();
+        let constructors = self.expand_constructors();
+        let into_opt = self.expand_union_fn_into_opt();
+        let into_enum = self.expand_union_fn_opt_into_enum();
+        let accessors = self.expand_union_fn_opt_accessors();
+        let from_raw = self.expand_union_fn_opt_from_raw();
+        let split_args = self.expand_union_fn_opt_split_args();
+        let debug = self.expand_union_fn_opt_debug();
+        let ident_opt = self.ident_opt();
+        let consts = self.config().no_enum().map(|_| {
+            let variant_count = self.expand_variant_count();
+            let consts = self.expand_consts();
+            quote_spanned!(span=>
+                impl #ident_opt {
+                    #variant_count
+                    #( #consts )*
+                }
+            )
+        });
+        quote_spanned!(span=>
+            #consts
+            #into_opt
+            #into_enum
+            #accessors
+            #from_raw
+            #split_args
+            #debug
+            #call_impl
+            #constructors
+        )
+    }
+
+    /// Expands the `union_fn::IntoOpt` impl of the user facing `#[union_fn]` enum type.
+    ///
+    /// Also expands the equivalent `From` impl for the call optimized type.
+    ///
+    /// Returns `None` if the generation of the enum is skipped.
+    fn expand_union_fn_into_opt(&self) -> Option<TokenStream2> {
+        if self.config().no_enum().is_some() {
+            return None;
+        }
+        let span = self.span();
+        let trait_ident = self.ident();
+        let ident_opt = self.ident_opt();
+        let ident_impls = self.ident_impls();
+        let ident_delegate = self.ident_delegate();
+        let conversions = self.expand_union_fn_opt_into_opt_arms();
+        let track_caller = Self::expand_track_caller(span);
+        Some(quote_spanned!(span=>
+            impl ::union_fn::IntoOpt for #trait_ident {
+                type Opt = #ident_opt;
+                type Delegator = #ident_delegate;
+                type Impls = #ident_impls;
+
+                #[allow(deprecated)]
+                #track_caller
+                fn into_opt(self) -> Self::Opt {
+                    match self {
+                        #( #conversions )*
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#trait_ident> for #ident_opt {
+                #track_caller
+                fn from(value: #trait_ident) -> Self {
+                    <#trait_ident as ::union_fn::IntoOpt>::into_opt(value)
+                }
+            }
+        ))
+    }
+
+    /// Expands the `Debug` impl of the call optimized `#[union_fn]` type.
+    ///
+    /// # Note
+    ///
+    /// Only shows the address of the handler function unless the `checked_dispatch`
+    /// crate feature is enabled since otherwise the arguments cannot be safely decoded.
+    fn expand_union_fn_opt_debug(&self) -> TokenStream2 {
+        let span = self.span();
+        let ident_opt = self.ident_opt();
+        let opt_name = ident_opt.to_string();
+        let location_field = cfg!(feature = "source_locations").then(|| {
+            quote_spanned!(span=>
+                .field("location", &::core::format_args!("{}", self.location))
+            )
+        });
+        let discriminant_fields = cfg!(feature = "checked_dispatch").then(|| {
+            quote_spanned!(span=>
+                .field("discriminant", &self.discriminant)
+                .field("args", unsafe { &self.args.debug_with_discriminant(self.discriminant) })
+            )
+        });
+        quote_spanned!(span=>
+            impl ::core::fmt::Debug for #ident_opt {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    f.debug_struct(#opt_name)
+                        .field("handler", &self.handler_ptr())
+                        #location_field
+                        #discriminant_fields
+                        .finish()
+                }
+            }
+        )
+    }
+
+    /// Expands the accessors of the call optimized `#[union_fn]` type.
+    fn expand_union_fn_opt_accessors(&self) -> TokenStream2 {
+        let span = self.span();
+        let ident_opt = self.ident_opt();
+        let discriminant = cfg!(feature = "checked_dispatch").then(|| {
+            quote_spanned!(span=>
+                /// Returns the zero-based index of the `#[union_fn]` method represented by `self`.
+                pub fn discriminant(&self) -> ::core::primitive::usize {
+                    self.discriminant
+                }
+            )
+        });
+        let location = cfg!(feature = "source_locations").then(|| {
+            quote_spanned!(span=>
+                /// Returns the source location at which `self` has been constructed.
+                pub fn location(&self) -> &'static ::core::panic::Location<'static> {
+                    self.location
+                }
+            )
+        });
+        quote_spanned!(span=>
+            impl #ident_opt {
+                /// Returns a shared reference to the raw packed arguments of `self`.
+                pub fn args_ref(&self) -> &<#ident_opt as ::union_fn::UnionFn>::Args {
+                    &self.args
+                }
+
+                /// Returns the raw pointer to the handler function that `self` dispatches to.
+                ///
+                /// The returned pointer identifies the `#[union_fn]` method represented by `self`
+                /// and can be compared against the functions of the `Delegator` type.
+                pub fn handler_ptr(&self) -> *const () {
+                    self.handler as *const ()
+                }
+
+                #discriminant
+                #location
+            }
+
+            impl ::core::fmt::Pointer for #ident_opt {
+                /// Formats the address of the handler function that `self` dispatches to.
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Pointer::fmt(&self.handler_ptr(), f)
+                }
+            }
+        )
+    }
+
+    /// Expands the unsafe `from_raw` constructor of the call optimized `#[union_fn]` type.
+    fn expand_union_fn_opt_from_raw(&self) -> TokenStream2 {
+        let span = self.span();
+        let ident_opt = self.ident_opt();
+        let handler_type = self.expand_handler_type();
+        let track_caller = Self::expand_track_caller(span);
+        let (init_discriminant, ignore_discriminant) = match cfg!(feature = "checked_dispatch") {
+            true => (Some(quote_spanned!(span=> discriminant,)), None),
+            false => (None, Some(quote_spanned!(span=> let _ = discriminant;))),
+        };
+        let location = cfg!(feature = "source_locations").then(|| {
+            quote_spanned!(span=>
+                location: ::core::panic::Location::caller(),
+            )
+        });
+        quote_spanned!(span=>
+            impl #ident_opt {
+                /// Creates a new call optimized value from its raw parts.
+                ///
+                /// This is useful for custom dispatch schemes that need to reconstruct values
+                /// from a serialized or generated form. Usually `handler` is a function of the
+                /// `Delegator` type and `args` the matching constructor of the `Args` type.
+                ///
+                /// # Safety
+                ///
+                /// The caller must ensure that `handler` is compatible with `args` and that
+                /// `discriminant` is the zero-based index of the `#[union_fn]` method that
+                /// `handler` belongs to. Calling the returned value otherwise may result in
+                /// undefined behavior.
+                #track_caller
+                pub unsafe fn from_raw(
+                    discriminant: ::core::primitive::usize,
+                    handler: #handler_type,
+                    args: <#ident_opt as ::union_fn::UnionFn>::Args,
+                ) -> Self {
+                    #ignore_discriminant
+                    Self {
+                        handler,
+                        args,
+                        #init_discriminant
+                        #location
+                    }
+                }
+            }
+        )
+    }
+
+    /// Expands the `union_fn::SplitArgs` impl of the call optimized `#[union_fn]` type.
+    ///
+    /// Returns `None` unless the call optimized type implements `union_fn::CallWithContext`.
+    fn expand_union_fn_opt_split_args(&self) -> Option<TokenStream2> {
+        if self.asyncness().is_some() || self.unsafety().is_some() {
+            return None;
+        }
+        self.state.get_context()?;
+        let span = self.span();
+        let ident_opt = self.ident_opt();
+        let handler_type = self.expand_handler_type();
+        let handler_unsafety = self.handler_unsafety();
+        let where_clause = self.expand_context_where_clause();
+        let mut head_fields = vec![quote_spanned!(span=> handler)];
+        let mut head_types = vec![handler_type];
+        if cfg!(feature = "checked_dispatch") {
+            head_fields.push(quote_spanned!(span=> discriminant));
+            head_types.push(quote_spanned!(span=> ::core::primitive::usize));
+        }
+        if cfg!(feature = "source_locations") {
+            head_fields.push(quote_spanned!(span=> location));
+            head_types.push(quote_spanned!(span=> &'static ::core::panic::Location<'static>));
+        }
+        let (discriminant_pat, discriminant) = match cfg!(feature = "checked_dispatch") {
+            true => (
+                Some(quote_spanned!(span=> discriminant,)),
+                Some(quote_spanned!(span=> , discriminant)),
+            ),
+            false => (None, None),
+        };
+        let handler_call = make_unsafe_block(
+            handler_unsafety,
+            quote_spanned!(span=> handler(ctx, args #discriminant)),
+        );
+        Some(quote_spanned!(span=>
+            impl ::union_fn::SplitArgs for #ident_opt #where_clause {
+                type Head = ( #( #head_types, )* );
+
+                fn split_args(self) -> (Self::Head, <Self as ::union_fn::UnionFn>::Args) {
+                    (( #( self.#head_fields, )* ), self.args)
+                }
+
+                unsafe fn join_args(
+                    head: Self::Head,
+                    args: <Self as ::union_fn::UnionFn>::Args,
+                ) -> Self {
+                    let ( #( #head_fields, )* ) = head;
+                    Self {
+                        #( #head_fields, )*
+                        args,
+                    }
+                }
+
+                #[inline]
+                unsafe fn call_split(
+                    head: Self::Head,
+                    ctx: &mut <Self as ::union_fn::CallWithContext>::Context,
+                    args: &<Self as ::union_fn::UnionFn>::Args,
+                ) -> <Self as ::union_fn::UnionFn>::Output {
+                    let (handler, #discriminant_pat ..) = head;
+                    #handler_call
+                }
+            }
+        ))
+    }
+
+    /// Expands the `union_fn::IntoEnum` impl of the call optimized `#[union_fn]` type.
+    ///
+    /// Returns `None` if the `checked_dispatch` crate feature is disabled
+    /// since the call optimized type does not store its discriminant then.
+    /// Also returns `None` if the generation of the enum is skipped.
+    fn expand_union_fn_opt_into_enum(&self) -> Option<TokenStream2> {
+        if !cfg!(feature = "checked_dispatch") || self.config().no_enum().is_some() {
+            return None;
+        }
+        let span = self.span();
+        let trait_ident = self.ident();
+        let ident_opt = self.ident_opt();
+        let match_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method_ident.to_upper_camel_case();
+            let discriminant = self.expand_discriminant(&method);
+            let bindings = method.input_bindings(&self.state);
+            let tuple_bindings = make_tuple_type(method_span, &bindings);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #discriminant => {
+                    // SAFETY: the discriminant is always in sync with the active `args` field.
+                    let #tuple_bindings = unsafe { self.args.#method_ident };
+                    ::core::option::Option::Some(#trait_ident::#variant_ident { #( #bindings ),* })
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            impl ::union_fn::IntoEnum for #ident_opt {
+                type Enum = #trait_ident;
+
+                fn into_enum(self) -> ::core::option::Option<Self::Enum> {
+                    match self.discriminant {
+                        #( #match_arms )*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        ))
+    }
+
+    /// Expands the arms of the conversion to the call optimized type of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_opt_into_opt_arms(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        self.methods().map(move |method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let variant_ident = method_ident.to_upper_camel_case();
+            let method_cfgs = method.cfg_attrs();
+            let fields = method.input_bindings(&self.state);
+            let args = self.expand_constructor_args(&method);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                Self::#variant_ident {
+                    #( #fields ),*
+                } => {
+                    <Self as ::union_fn::IntoOpt>::Opt::#method_ident( #args )
+                }
+            )
+        })
+    }
+
+    /// Expands the doc paragraph of the `#[union_fn]` enum linking to its call optimized type.
+    ///
+    /// # Note
+    ///
+    /// The call optimized type can only be linked if it is generated into a `module`
+    /// since it otherwise cannot be named from outside of its `const` block.
+    fn expand_union_fn_enum_opt_link(&self) -> Option<TokenStream2> {
+        if self.config().no_opt().is_some() {
+            return None;
+        }
+        let span = self.span();
+        let ident_opt = self.ident_opt();
+        let opt = match self.config().module() {
+            Some(module) => format!("[`{ident_opt}`]({module}::{ident_opt})"),
+            None => format!("`{ident_opt}`"),
+        };
+        let docs = format!(
+            "See also: {opt} for the call-optimized representation \
+             (via [`IntoOpt`](union_fn::IntoOpt))."
+        );
+        Some(quote_spanned!(span=>
+            #[doc = ""]
+            #[doc = #docs]
+        ))
+    }
+
+    /// Expand the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let trait_ident = self.ident();
+        let attrs = self.attrs();
+        let variants = self.expand_union_fn_enum_variants();
+        let serde = self.expand_union_fn_enum_serde();
+        let repr = self
+            .config()
+            .enum_repr()
+            .map(|repr| quote_spanned!(trait_span=> #[repr(#repr)]));
+        let non_exhaustive = self
+            .config()
+            .non_exhaustive()
+            .map(|_| quote_spanned!(trait_span=> #[non_exhaustive]));
+        let constructors = self.expand_union_fn_enum_constructors();
+        let variant_name = self.expand_union_fn_enum_variant_name();
+        let discriminant = self.expand_union_fn_enum_discriminant();
+        let discriminant_impl = self.expand_union_fn_enum_discriminant_impl();
+        let variants_info = self.expand_union_fn_enum_variants_info();
+        let from_discriminant = self.expand_union_fn_enum_from_discriminant();
+        let zero_arg_variants = self.expand_union_fn_enum_zero_arg_variants();
+        let predicates = self.expand_union_fn_enum_predicates();
+        let accessors = self.expand_union_fn_enum_accessors();
+        let const_call = self.expand_union_fn_enum_const_call();
+        let call_impl = self.expand_union_fn_enum_call_impl();
+        let default_impl = self.expand_union_fn_enum_default();
+        let display_impl = self.expand_union_fn_enum_display();
+        let from_str_impl = self.expand_union_fn_enum_from_str();
+        let visitor = self.expand_union_fn_enum_visitor();
+        let arbitrary = self.expand_union_fn_enum_arbitrary();
+        let binary = self.expand_union_fn_enum_binary();
+        let consts = self.expand_consts();
+        let variant_count = self.expand_variant_count();
+        // Parameters that are known to not be `Copy` are only supported together with `no_opt`.
+        let derive_copy = self
+            .is_copy()
+            .then(|| quote_spanned!(trait_span=> ::core::marker::Copy,));
+        let opt_link = self.expand_union_fn_enum_opt_link();
+        quote_spanned!(trait_span=>
+            #( #attrs )*
+            #opt_link
+            #[derive(#derive_copy ::core::clone::Clone)]
+            #serde
+            #repr
+            #non_exhaustive
+            pub enum #trait_ident {
+                #( #variants ),*
+            }
+
+            impl #trait_ident {
+                #variant_count
+                #( #consts )*
+                #( #constructors )*
+                #variant_name
+                #discriminant
+                #variants_info
+                #from_discriminant
+                #zero_arg_variants
+                #( #predicates )*
+                #( #accessors )*
+                #const_call
+            }
+
+            #call_impl
+            #discriminant_impl
+            #default_impl
+            #display_impl
+            #from_str_impl
+            #visitor
+            #arbitrary
+            #binary
+        )
+    }
+
+    /// Expands the `BinaryEncode` and `BinaryDecode` impls of the user facing `#[union_fn]` enum type.
+    ///
+    /// Returns `None` if the `binary` macro argument is missing.
+    fn expand_union_fn_enum_binary(&self) -> Option<TokenStream2> {
+        self.config().binary()?;
+        let trait_span = self.span();
+        let trait_ident = self.ident();
+        let encode_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method.ident().to_upper_camel_case();
+            let discriminant = self.expand_discriminant(&method);
+            let bindings = method.input_bindings(&self.state);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                Self::#variant_ident { #( #bindings ),* } => {
+                    buf.write_all(&[#discriminant as ::core::primitive::u8])?;
+                    #(
+                        buf.write_all(::union_fn::bytemuck::bytes_of(#bindings))?;
+                    )*
+                }
+            )
+        });
+        let decode_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method.ident().to_upper_camel_case();
+            let discriminant = self.expand_discriminant(&method);
+            let bindings = method.input_bindings(&self.state);
+            let input_types = method.input_types(&self.state);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #discriminant => {
+                    #(
+                        let mut #bindings = <#input_types as ::union_fn::bytemuck::Zeroable>::zeroed();
+                        buf.read_exact(::union_fn::bytemuck::bytes_of_mut(&mut #bindings))?;
+                    )*
+                    Self::#variant_ident { #( #bindings ),* }
+                }
+            )
+        });
+        Some(quote_spanned!(trait_span=>
+            impl ::union_fn::BinaryEncode for #trait_ident {
+                fn encode_to(
+                    &self,
+                    buf: &mut impl ::union_fn::private::io::Write,
+                ) -> ::union_fn::private::io::Result<()> {
+                    match self {
+                        #( #encode_arms )*
+                    }
+                    ::core::result::Result::Ok(())
+                }
+            }
+
+            impl ::union_fn::BinaryDecode for #trait_ident {
+                fn decode_from(
+                    buf: &mut impl ::union_fn::private::io::Read,
+                ) -> ::union_fn::private::io::Result<Self> {
+                    let mut discriminant = [0_u8];
+                    buf.read_exact(&mut discriminant)?;
+                    let decoded = match ::core::primitive::usize::from(discriminant[0]) {
+                        #( #decode_arms )*
+                        _ => {
+                            return ::core::result::Result::Err(::union_fn::private::io::Error::new(
+                                ::union_fn::private::io::ErrorKind::InvalidData,
+                                "encountered invalid discriminant",
+                            ))
+                        }
+                    };
+                    ::core::result::Result::Ok(decoded)
+                }
+            }
+        ))
+    }
+
+    /// Expands the `arbitrary::Arbitrary` impl of the user facing `#[union_fn]` enum type.
+    ///
+    /// # Note
+    ///
+    /// Selects one of the variants with equal probability and generates its parameters
+    /// using their respective `Arbitrary` impls.
+    ///
+    /// Returns `None` if the `arbitrary` macro argument is missing.
+    fn expand_union_fn_enum_arbitrary(&self) -> Option<TokenStream2> {
+        self.config().arbitrary()?;
+        let trait_span = self.span();
+        let trait_ident = self.ident();
+        let constructors = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method.ident().to_upper_camel_case();
+            let bindings = method.input_bindings(&self.state);
+            let unstructured = match bindings.is_empty() {
+                true => quote_spanned!(method_span=> _),
+                false => quote_spanned!(method_span=> u),
+            };
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                |#unstructured| ::core::result::Result::Ok(Self::#variant_ident {
+                    #(
+                        #bindings: ::union_fn::arbitrary::Arbitrary::arbitrary(u)?,
+                    )*
+                }),
+            )
+        });
+        Some(quote_spanned!(trait_span=>
+            impl<'a> ::union_fn::arbitrary::Arbitrary<'a> for #trait_ident {
+                fn arbitrary(
+                    u: &mut ::union_fn::arbitrary::Unstructured<'a>,
+                ) -> ::union_fn::arbitrary::Result<Self> {
+                    type Constructor<'a> = fn(
+                        &mut ::union_fn::arbitrary::Unstructured<'a>,
+                    ) -> ::union_fn::arbitrary::Result<#trait_ident>;
+                    let constructors: &[Constructor<'a>] = &[
+                        #( #constructors )*
+                    ];
+                    (u.choose(constructors)?)(u)
+                }
+            }
+        ))
+    }
+
+    /// Expands the `FromStr` impl of the user facing `#[union_fn]` enum type.
+    ///
+    /// # Note
+    ///
+    /// Parses the `method_name(arg1, arg2, ...)` format of its `Display` impl
+    /// where parameters with types that do not implement `FromStr` always fail to parse.
+    fn expand_union_fn_enum_from_str(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let trait_ident = self.ident();
+        let match_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let variant_ident = method_ident.to_upper_camel_case();
+            let method_cfgs = method.cfg_attrs();
+            let name = method.display_name();
             let bindings = method.input_bindings(&self.state);
-            let tuple_bindings = make_tuple_type(method_span, &bindings);
+            let input_types = method.input_types(&self.state);
             quote_spanned!(method_span=>
-                #( #method_attrs )*
-                fn #method_ident( #ctx_param args: &<#trait_ident as ::union_fn::UnionFn>::Args )
-                    -> <#trait_ident as ::union_fn::UnionFn>::Output
-                {
-                    let #tuple_bindings = unsafe { args.#method_ident };
-                    <#trait_ident as ::union_fn::IntoOpt>::Impls::#method_ident( #ctx_ident #( #bindings ),* )
+                #( #method_cfgs )*
+                #name => {
+                    #(
+                        let (index, arg) = args.next_arg()?;
+                        let #bindings = (&::union_fn::private::ParseWrap::<#input_types>::new())
+                            .parse_arg(arg)
+                            .ok_or(::union_fn::ParseError::InvalidArg(index))?;
+                    )*
+                    args.finish()?;
+                    ::core::result::Result::Ok(Self::#variant_ident { #( #bindings ),* })
                 }
             )
         });
         quote_spanned!(trait_span=>
-            #[doc = #delegate_docs]
-            pub enum #ident_delegate {}
+            impl ::core::str::FromStr for #trait_ident {
+                type Err = ::union_fn::ParseError;
 
-            impl #ident_delegate {
-                #( #delegates )*
+                #[allow(unused_mut)]
+                fn from_str(input: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                    #[allow(unused_imports)]
+                    use ::union_fn::private::{ParseFallback as _, ParseViaImpl as _};
+                    let (name, mut args) = ::union_fn::private::parse_call(input)?;
+                    match name {
+                        #( #match_arms )*
+                        _ => ::core::result::Result::Err(::union_fn::ParseError::UnknownMethod),
+                    }
+                }
             }
         )
     }
 
-    /// Expand the `#[union_fn]` type.
-    fn expand_union_fn_opt(&self) -> TokenStream2 {
-        let span = self.span();
+    /// Expands the visitor trait of the user facing `#[union_fn]` enum type and its `visit` method.
+    ///
+    /// Returns `None` if the `visitor` macro argument is missing.
+    fn expand_union_fn_enum_visitor(&self) -> Option<TokenStream2> {
+        self.config().visitor()?;
+        let trait_span = self.span();
         let trait_ident = self.ident();
-        let ident_opt = self.ident_opt();
-        let ident_impls = self.ident_impls();
-        let ident_delegate = self.ident_delegate();
-        let opt_docs = format!("Call optimized structure of the [`{trait_ident}`] type.");
-        let call_impl = self.expand_call_impl();
-        let constructors = self.expand_constructors();
-        let conversions = self.expand_union_fn_opt_into_opt_arms();
-        let ctx = self.state.get_context().map(|_| {
-            quote_spanned!(span=>
-                ctx: &mut <#trait_ident as ::union_fn::CallWithContext>::Context,
+        let ident_visitor = self.ident_visitor();
+        let visit_ident = |method: &UnionFnMethod| format_ident!("visit_{}", method.ident());
+        let visitor_methods = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let visit_ident = visit_ident(&method);
+            let inputs = method.ident_inputs(&self.state);
+            let visit_docs = format!(
+                "Visits the [`{trait_ident}::{}`] instance with its parameters.",
+                method.ident()
+            );
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #[doc = #visit_docs]
+                fn #visit_ident(&mut self, #( #inputs ),* ) -> Self::Output;
             )
         });
-        quote_spanned!(span=>
-            #[doc = #opt_docs]
-            #[derive(::core::marker::Copy, ::core::clone::Clone)]
-            pub struct #ident_opt {
-                handler: fn(#ctx &<#trait_ident as ::union_fn::UnionFn>::Args) -> <#trait_ident as ::union_fn::UnionFn>::Output,
-                args: <#trait_ident as ::union_fn::UnionFn>::Args,
-            }
+        let match_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method.ident().to_upper_camel_case();
+            let visit_ident = visit_ident(&method);
+            let bindings = method.input_bindings(&self.state);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                Self::#variant_ident { #( #bindings ),* } => visitor.#visit_ident( #( #bindings ),* ),
+            )
+        });
+        let visitor_docs =
+            format!("Visitor with a method per variant of the [`{trait_ident}`] enum.");
+        Some(quote_spanned!(trait_span=>
+            #[doc = #visitor_docs]
+            pub trait #ident_visitor {
+                /// The output of all visitor methods.
+                type Output;
 
-            impl ::union_fn::IntoOpt for #trait_ident {
-                type Opt = #ident_opt;
-                type Delegator = #ident_delegate;
-                type Impls = #ident_impls;
+                #( #visitor_methods )*
+            }
 
-                fn into_opt(self) -> Self::Opt {
+            impl #trait_ident {
+                /// Dispatches `self` to the `visitor` method of its variant.
+                pub fn visit<V>(self, visitor: &mut V) -> <V as #ident_visitor>::Output
+                where
+                    V: #ident_visitor,
+                {
                     match self {
-                        #( #conversions )*
+                        #( #match_arms )*
                     }
                 }
             }
-
-            #call_impl
-            #constructors
-        )
+        ))
     }
 
-    /// Expands the arms of the conversion to the call optimized type of the user facing `#[union_fn]` enum type.
-    fn expand_union_fn_opt_into_opt_arms(&self) -> impl Iterator<Item = TokenStream2> + '_ {
-        self.methods().map(move |method| {
+    /// Expands the `Display` impl of the user facing `#[union_fn]` enum type.
+    ///
+    /// # Note
+    ///
+    /// Formats as `method_name(arg1, arg2, ...)` where parameters with
+    /// types that do not implement `Display` are formatted as `..`.
+    fn expand_union_fn_enum_display(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let trait_ident = self.ident();
+        let match_arms = self.methods().map(|method| {
             let method_span = method.span();
             let method_ident = method.ident();
             let variant_ident = method_ident.to_upper_camel_case();
-            let fields = method.input_bindings(&self.state);
+            let method_cfgs = method.cfg_attrs();
+            let name = format!("{}(", method.display_name());
+            let bindings = method.input_bindings(&self.state);
+            let args = bindings.iter().enumerate().map(|(n, binding)| {
+                let separator = (n != 0).then(|| quote_spanned!(method_span=> f.write_str(", ")?;));
+                quote_spanned!(method_span=>
+                    #separator
+                    (&::union_fn::private::FmtWrap(#binding)).display_fmt(f)?;
+                )
+            });
             quote_spanned!(method_span=>
-                Self::#variant_ident {
-                    #( #fields ),*
-                } => {
-                    <Self as ::union_fn::IntoOpt>::Opt::#method_ident( #( #fields ),* )
+                #( #method_cfgs )*
+                Self::#variant_ident { #( #bindings ),* } => {
+                    f.write_str(#name)?;
+                    #( #args )*
+                    f.write_str(")")
                 }
             )
-        })
+        });
+        quote_spanned!(trait_span=>
+            impl ::core::fmt::Display for #trait_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    #[allow(unused_imports)]
+                    use ::union_fn::private::{DisplayFallback as _, DisplayViaImpl as _};
+                    match self {
+                        #( #match_arms )*
+                    }
+                }
+            }
+        )
     }
 
-    /// Expand the user facing `#[union_fn]` enum type.
-    fn expand_union_fn_enum(&self) -> TokenStream2 {
+    /// Expands the `Default` impl of the user facing `#[union_fn]` enum type.
+    ///
+    /// The default instance is the first `#[union_fn]` method without parameters
+    /// in declaration order that is not conditionally compiled via `#[cfg]`.
+    /// This also holds with `stable_discriminants` which only reorders the discriminants.
+    ///
+    /// Returns `None` if there is no such `#[union_fn]` method.
+    fn expand_union_fn_enum_default(&self) -> Option<TokenStream2> {
         let trait_span = self.span();
         let trait_ident = self.ident();
-        let attrs = self.attrs();
-        let variants = self.expand_union_fn_enum_variants();
-        let constructors = self.expand_union_fn_enum_constructors();
-        let call_impl = self.expand_union_fn_enum_call_impl();
-        quote_spanned!(trait_span=>
-            #( #attrs )*
-            #[derive(::core::marker::Copy, ::core::clone::Clone)]
-            pub enum #trait_ident {
-                #( #variants ),*
-            }
-
-            impl #trait_ident {
-                #( #constructors )*
+        let method = self.declared_methods().find(|method| {
+            method.inputs(&self.state).next().is_none() && method.cfg_attrs().next().is_none()
+        })?;
+        let method_ident = method.ident();
+        let variant_ident = method_ident.to_upper_camel_case();
+        let default_docs = format!("Returns the [`{trait_ident}::{method_ident}`] instance.");
+        Some(quote_spanned!(trait_span=>
+            impl ::core::default::Default for #trait_ident {
+                #[doc = #default_docs]
+                fn default() -> Self {
+                    Self::#variant_ident {}
+                }
             }
-
-            #call_impl
-        )
+        ))
     }
 
     /// Expands the enum variants of the user facing `#[union_fn]` enum type.
@@ -209,10 +1500,14 @@ impl UnionFn {
             let method_span = method.span();
             let method_ident = method.ident();
             let method_docs = method.doc_attrs();
+            let method_cfgs = method.cfg_attrs();
             let variant_ident = method_ident.to_upper_camel_case();
             let variant_fields = method.ident_inputs(&self.state);
+            let serde_rename = self.expand_union_fn_enum_variant_serde(&method);
             quote_spanned!(method_span=>
+                #( #method_cfgs )*
                 #( #method_docs )*
+                #serde_rename
                 #variant_ident {
                     #( #variant_fields ),*
                 }
@@ -220,38 +1515,456 @@ impl UnionFn {
         })
     }
 
+    /// Expands the `serde` derives of the user facing `#[union_fn]` enum type.
+    ///
+    /// Returns `None` if the `serde` macro argument is missing.
+    fn expand_union_fn_enum_serde(&self) -> Option<TokenStream2> {
+        let serde = self.config().serde()?;
+        let span = serde.span();
+        Some(quote_spanned!(span=>
+            #[derive(::union_fn::serde::Serialize, ::union_fn::serde::Deserialize)]
+            #[serde(crate = "::union_fn::serde")]
+        ))
+    }
+
+    /// Expands the `serde` attributes of a variant of the user facing `#[union_fn]` enum type.
+    ///
+    /// Variants are (de)serialized using the name of their `#[union_fn]` method.
+    ///
+    /// Returns `None` if the `serde` macro argument is missing.
+    fn expand_union_fn_enum_variant_serde(&self, method: &UnionFnMethod) -> Option<TokenStream2> {
+        self.config().serde()?;
+        let method_span = method.span();
+        let name = method.ident().to_string();
+        Some(quote_spanned!(method_span=>
+            #[serde(rename = #name)]
+        ))
+    }
+
     /// Expands the enum constructors of the user facing `#[union_fn]` enum type.
     fn expand_union_fn_enum_constructors(&self) -> impl Iterator<Item = TokenStream2> + '_ {
         self.methods().map(|method| {
+            let default_constructor = self.expand_default_constructor(&method);
             let method_span = method.span();
+            let constness = self.constness();
             let method_ident = method.ident();
-            let method_attrs = method.attrs();
+            let method_attrs = method.constructor_attrs();
+            let must_use = Self::expand_constructor_must_use(&method);
             let variant_ident = method_ident.to_upper_camel_case();
-            let params = method.ident_inputs(&self.state);
+            let (params, destructure) = self.expand_constructor_params(&method);
             let fields = method.input_bindings(&self.state);
             quote_spanned!(method_span=>
                 #( #method_attrs )*
-                pub fn #method_ident( #( #params ),* ) -> Self {
+                #must_use
+                pub #constness fn #method_ident( #params ) -> Self {
+                    #destructure
                     Self::#variant_ident {
                         #( #fields ),*
                     }
                 }
+
+                #default_constructor
+            )
+        })
+    }
+
+    /// Expands the parameters of the constructors of the `method`.
+    ///
+    /// # Note
+    ///
+    /// Methods with `#[named_args]` take a single named arguments struct instead
+    /// which is then destructured into the bindings of its fields.
+    fn expand_constructor_params(
+        &self,
+        method: &UnionFnMethod,
+    ) -> (TokenStream2, Option<TokenStream2>) {
+        let method_span = method.span();
+        match method.named_args() {
+            Some(_) => {
+                let ident = method.ident_named_args();
+                let bindings = method.input_bindings(&self.state);
+                (
+                    quote_spanned!(method_span=> args: #ident),
+                    Some(quote_spanned!(method_span=>
+                        let #ident { #( #bindings ),* } = args;
+                    )),
+                )
+            }
+            None => {
+                let params = method.ident_inputs(&self.state);
+                (quote_spanned!(method_span=> #( #params ),*), None)
+            }
+        }
+    }
+
+    /// Expands the constructor of the `method` using the `#[default]` values of its parameters.
+    ///
+    /// Returns `None` if no parameter of the `method` has a `#[default]` value.
+    ///
+    /// # Note
+    ///
+    /// The constructor is named `{method}_default` and only takes the parameters
+    /// without `#[default]` value.
+    fn expand_default_constructor(&self, method: &UnionFnMethod) -> Option<TokenStream2> {
+        if !method.has_input_defaults(&self.state) {
+            return None;
+        }
+        let method_span = method.span();
+        let constness = self.constness();
+        let method_ident = method.ident();
+        let ident_default = method.ident_default();
+        let method_attrs = method
+            .constructor_attrs()
+            .filter(|attr| !attr.is_docs_attribute());
+        let must_use = Self::expand_constructor_must_use(method);
+        let defaults = method
+            .input_defaults(&self.state)
+            .expect("default values have been validated during analysis");
+        let inputs = method.ident_inputs(&self.state);
+        let params = inputs
+            .iter()
+            .zip(&defaults)
+            .filter(|(_, default)| default.is_none())
+            .map(|(param, _)| param);
+        let args = method
+            .input_bindings(&self.state)
+            .into_iter()
+            .zip(&defaults)
+            .map(|(binding, default)| match default {
+                Some(default) => quote_spanned!(default.span()=> #default),
+                None => quote_spanned!(method_span=> #binding),
+            });
+        let docs = format!(
+            "Constructs the `{method_ident}` instruction using the default values of its parameters."
+        );
+        let track_caller = Self::expand_track_caller(method_span);
+        Some(quote_spanned!(method_span=>
+            #( #method_attrs )*
+            #[doc = #docs]
+            #must_use
+            #track_caller
+            pub #constness fn #ident_default( #( #params ),* ) -> Self {
+                Self::#method_ident( #( #args ),* )
+            }
+        ))
+    }
+
+    /// Expands the `#[track_caller]` attribute of functions constructing the call optimized type.
+    ///
+    /// Returns `None` unless the `source_locations` crate feature is enabled in which case
+    /// the call optimized type stores the source location of its construction.
+    fn expand_track_caller(span: Span) -> Option<TokenStream2> {
+        cfg!(feature = "source_locations").then(|| quote_spanned!(span=> #[track_caller]))
+    }
+
+    /// Expands the arguments to call the constructors of the `method` with its bound inputs.
+    fn expand_constructor_args(&self, method: &UnionFnMethod) -> TokenStream2 {
+        let method_span = method.span();
+        let bindings = method.input_bindings(&self.state);
+        match method.named_args() {
+            Some(_) => {
+                let ident = method.ident_named_args();
+                quote_spanned!(method_span=> #ident { #( #bindings ),* })
+            }
+            None => quote_spanned!(method_span=> #( #bindings ),*),
+        }
+    }
+
+    /// Expands the named arguments structs of the `#[union_fn]` methods with `#[named_args]`.
+    fn expand_named_args(&self) -> TokenStream2 {
+        let span = self.span();
+        let public_type = match self.config().no_enum().is_some() {
+            true => self.ident_opt(),
+            false => self.ident().clone(),
+        };
+        let derive_copy = self
+            .is_copy()
+            .then(|| quote_spanned!(span=> ::core::marker::Copy,));
+        let structs = self
+            .methods()
+            .filter(|method| method.named_args().is_some())
+            .map(|method| {
+                let method_span = method.span();
+                let method_ident = method.ident();
+                let method_cfgs = method.cfg_attrs();
+                let ident = method.ident_named_args();
+                let docs = format!(
+                    "Named arguments of the [`{public_type}::{method_ident}`] constructor."
+                );
+                let fields = method.input_bindings(&self.state);
+                let field_types = method.input_types(&self.state);
+                quote_spanned!(method_span=>
+                    #( #method_cfgs )*
+                    #[doc = #docs]
+                    #[derive(#derive_copy ::core::clone::Clone)]
+                    pub struct #ident {
+                        #( pub #fields: #field_types ),*
+                    }
+                )
+            });
+        quote_spanned!(span=> #( #structs )*)
+    }
+
+    /// Expands the `#[must_use]` attribute of the constructors of the `method`.
+    ///
+    /// Returns `None` if the `method` already carries its own `#[must_use]` attribute
+    /// since that one is forwarded to the constructors instead.
+    fn expand_constructor_must_use(method: &UnionFnMethod) -> Option<TokenStream2> {
+        if method.is_must_use() {
+            return None;
+        }
+        Some(quote_spanned!(method.span()=>
+            #[must_use = "call .call() or .into_opt() on this instruction"]
+        ))
+    }
+
+    /// Expands the `is_{method}` predicates of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum_predicates(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        self.methods().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let variant_ident = method_ident.to_upper_camel_case();
+            let predicate_ident = format_ident!("is_{}", method_ident);
+            let method_cfgs = method.cfg_attrs();
+            let predicate_docs =
+                format!("Returns `true` if `self` represents the `{method_ident}` method.");
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #[doc = #predicate_docs]
+                pub fn #predicate_ident(&self) -> ::core::primitive::bool {
+                    ::core::matches!(self, Self::#variant_ident { .. })
+                }
+            )
+        })
+    }
+
+    /// Expands the `as_{method}` accessors of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum_accessors(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        self.methods().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let variant_ident = method_ident.to_upper_camel_case();
+            let accessor_ident = format_ident!("as_{}", method_ident);
+            let method_cfgs = method.cfg_attrs();
+            let accessor_docs = format!(
+                "Returns the parameters of the `{method_ident}` method if `self` represents it."
+            );
+            let bindings = method.input_bindings(&self.state);
+            let tuple_params = make_tuple_type(method_span, method.input_types(&self.state));
+            let (scrutinee, tuple_bindings) = match self.is_copy() {
+                true => (
+                    quote_spanned!(method_span=> *self),
+                    make_tuple_type(method_span, &bindings),
+                ),
+                false => (
+                    quote_spanned!(method_span=> self),
+                    make_tuple_type(
+                        method_span,
+                        bindings.iter().map(|binding| {
+                            quote_spanned!(method_span=> ::core::clone::Clone::clone(#binding))
+                        }),
+                    ),
+                ),
+            };
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #[doc = #accessor_docs]
+                #[allow(clippy::type_complexity)]
+                pub fn #accessor_ident(&self) -> ::core::option::Option<#tuple_params> {
+                    match #scrutinee {
+                        Self::#variant_ident { #( #bindings ),* } => {
+                            ::core::option::Option::Some(#tuple_bindings)
+                        }
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
             )
         })
     }
 
+    /// Expands the `variant_name` method of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum_variant_name(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let match_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let variant_ident = method_ident.to_upper_camel_case();
+            let method_cfgs = method.cfg_attrs();
+            let name = method.display_name();
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                Self::#variant_ident { .. } => #name,
+            )
+        });
+        quote_spanned!(trait_span=>
+            /// Returns the name of the `#[union_fn]` method represented by `self`.
+            pub fn variant_name(&self) -> &'static str {
+                match self {
+                    #( #match_arms )*
+                }
+            }
+        )
+    }
+
+    /// Expands the `discriminant` method of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum_discriminant(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let match_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let variant_ident = method.ident().to_upper_camel_case();
+            let discriminant = self.expand_discriminant(&method);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                Self::#variant_ident { .. } => #discriminant,
+            )
+        });
+        let order_docs = match self.config().stable_discriminants() {
+            Some(_) => "Indices are assigned in alphabetical order of the method names.",
+            None => "Indices are assigned in the order in which the methods are declared.",
+        };
+        quote_spanned!(trait_span=>
+            /// Returns the zero-based index of the `#[union_fn]` method represented by `self`.
+            ///
+            #[doc = #order_docs]
+            pub fn discriminant(&self) -> ::core::primitive::usize {
+                match self {
+                    #( #match_arms )*
+                }
+            }
+        )
+    }
+
+    /// Expands the `union_fn::Discriminant` impl of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum_discriminant_impl(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let trait_ident = self.ident();
+        quote_spanned!(trait_span=>
+            impl ::union_fn::Discriminant for #trait_ident {
+                fn discriminant(&self) -> ::core::primitive::usize {
+                    #trait_ident::discriminant(self)
+                }
+            }
+        )
+    }
+
+    /// Expands the `variants` method of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum_variants_info(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let variants = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_cfgs = method.cfg_attrs();
+            let name = method.display_name();
+            let discriminant = self.expand_discriminant(&method);
+            let input_types = method.input_types(&self.state);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                ::union_fn::VariantInfo {
+                    name: #name,
+                    discriminant: #discriminant,
+                    args_size: ::core::mem::size_of::<( #( #input_types, )* )>(),
+                },
+            )
+        });
+        quote_spanned!(trait_span=>
+            /// Returns information about all `#[union_fn]` methods ordered by their discriminants.
+            pub fn variants() -> &'static [::union_fn::VariantInfo] {
+                const VARIANTS: &[::union_fn::VariantInfo] = &[
+                    #( #variants )*
+                ];
+                VARIANTS
+            }
+        )
+    }
+
+    /// Expands the `zero_arg_variants` method of the user facing `#[union_fn]` enum type.
+    fn expand_union_fn_enum_zero_arg_variants(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let variants = self
+            .methods()
+            .filter(|method| method.inputs(&self.state).next().is_none())
+            .map(|method| {
+                let method_span = method.span();
+                let method_cfgs = method.cfg_attrs();
+                let variant_ident = method.ident().to_upper_camel_case();
+                quote_spanned!(method_span=>
+                    #( #method_cfgs )*
+                    Self::#variant_ident {},
+                )
+            });
+        quote_spanned!(trait_span=>
+            /// Returns an iterator yielding an instance of every `#[union_fn]` method without parameters.
+            ///
+            /// The instances are yielded in the order of their discriminants.
+            pub fn zero_arg_variants() -> impl ::core::iter::Iterator<Item = Self> {
+                [ #( #variants )* ].into_iter()
+            }
+        )
+    }
+
+    /// Expands the `from_discriminant` method of the user facing `#[union_fn]` enum type.
+    ///
+    /// Only methods without parameters can be reconstructed from their discriminant.
+    fn expand_union_fn_enum_from_discriminant(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let match_arms = self
+            .methods()
+            .filter(|method| method.inputs(&self.state).next().is_none())
+            .map(|method| {
+                let method_span = method.span();
+                let method_cfgs = method.cfg_attrs();
+                let variant_ident = method.ident().to_upper_camel_case();
+                let discriminant = self.expand_discriminant(&method);
+                quote_spanned!(method_span=>
+                    #( #method_cfgs )*
+                    #discriminant => ::core::option::Option::Some(Self::#variant_ident {}),
+                )
+            });
+        quote_spanned!(trait_span=>
+            /// Creates the instance of a `#[union_fn]` method without parameters from its `discriminant`.
+            ///
+            /// Returns `None` if `discriminant` is out of bounds or refers to a method with parameters.
+            pub fn from_discriminant(discriminant: ::core::primitive::usize) -> ::core::option::Option<Self> {
+                match discriminant {
+                    #( #match_arms )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        )
+    }
+
+    /// Expands the `where` clause requiring the `context_bounds` for the context type.
+    ///
+    /// Returns `None` if the `context_bounds` macro argument is missing or there is no context.
+    fn expand_context_where_clause(&self) -> Option<TokenStream2> {
+        let bounds = self.config().context_bounds()?;
+        let context = self.state.get_context()?;
+        let span = bounds.span();
+        Some(quote_spanned!(span=> where #context: #bounds))
+    }
+
     /// Expands the trait impl of either `union_fn::Call` or `union_fn::CallWithContext`.
     fn expand_union_fn_enum_call_impl(&self) -> TokenStream2 {
         let trait_span = self.span();
         let trait_ident = self.ident();
+        let unsafety = self.unsafety();
+        let asyncness = self.asyncness();
+        let call_trait = self.expand_call_trait(trait_span);
+        let call_ident = match asyncness {
+            Some(_) => format_ident!("call_async", span = trait_span),
+            None => format_ident!("call", span = trait_span),
+        };
         let match_arms = self.expand_union_fn_enum_call_impl_arms();
         match self.state.get_context() {
             Some(context) => {
+                let where_clause = self.expand_context_where_clause();
                 quote_spanned!(trait_span=>
-                    impl ::union_fn::CallWithContext for #trait_ident {
+                    impl #call_trait for #trait_ident #where_clause {
                         type Context = #context;
 
-                        fn call(self, ctx: &mut Self::Context) -> <#trait_ident as ::union_fn::UnionFn>::Output {
+                        #[inline]
+                        #asyncness #unsafety fn #call_ident(self, ctx: &mut Self::Context) -> <#trait_ident as ::union_fn::UnionFn>::Output {
                             match self {
                                 #( #match_arms )*
                             }
@@ -261,8 +1974,9 @@ impl UnionFn {
             }
             None => {
                 quote_spanned!(trait_span=>
-                    impl ::union_fn::Call for #trait_ident {
-                        fn call(self) -> <#trait_ident as ::union_fn::UnionFn>::Output {
+                    impl #call_trait for #trait_ident {
+                        #[inline]
+                        #asyncness #unsafety fn #call_ident(self) -> <#trait_ident as ::union_fn::UnionFn>::Output {
                             match self {
                                 #( #match_arms )*
                             }
@@ -273,6 +1987,60 @@ impl UnionFn {
         }
     }
 
+    /// Expands the path to the call trait implemented by the `#[union_fn]` types.
+    ///
+    /// This is either `union_fn::Call` or `union_fn::CallWithContext` depending on
+    /// whether the `#[union_fn]` has a context or their `Unsafe` counterparts if all
+    /// `#[union_fn]` methods are `unsafe fn` or their `Async` counterparts if all
+    /// `#[union_fn]` methods are `async fn`.
+    fn expand_call_trait(&self, span: Span) -> TokenStream2 {
+        let has_context = self.state.get_context().is_some();
+        if self.asyncness().is_some() {
+            return match has_context {
+                false => quote_spanned!(span=> ::union_fn::AsyncCall),
+                true => quote_spanned!(span=> ::union_fn::AsyncCallWithContext),
+            };
+        }
+        match (self.unsafety().is_some(), has_context) {
+            (false, false) => quote_spanned!(span=> ::union_fn::Call),
+            (false, true) => quote_spanned!(span=> ::union_fn::CallWithContext),
+            (true, false) => quote_spanned!(span=> ::union_fn::UnsafeCall),
+            (true, true) => quote_spanned!(span=> ::union_fn::UnsafeCallWithContext),
+        }
+    }
+
+    /// Expands the inherent `const fn call` method of the user facing `#[union_fn]` enum type.
+    ///
+    /// Returns `None` unless all `#[union_fn]` methods are `const fn`.
+    ///
+    /// # Note
+    ///
+    /// This is required since trait methods cannot be called in `const` contexts.
+    fn expand_union_fn_enum_const_call(&self) -> Option<TokenStream2> {
+        let constness = self.constness()?;
+        let trait_span = self.span();
+        let trait_ident = self.ident();
+        let match_arms = self.expand_union_fn_enum_call_impl_arms();
+        let unsafety = self.unsafety();
+        let call_trait = self.expand_call_trait(trait_span);
+        let ctx_param = self.state.get_context().map(|_| {
+            quote_spanned!(trait_span=>
+                ctx: &mut <#trait_ident as #call_trait>::Context
+            )
+        });
+        Some(quote_spanned!(trait_span=>
+            /// Calls the `#[union_fn]` method represented by `self`.
+            ///
+            /// This is usable in `const` contexts since all `#[union_fn]` methods are `const fn`.
+            #[inline]
+            pub #constness #unsafety fn call(self, #ctx_param) -> <#trait_ident as ::union_fn::UnionFn>::Output {
+                match self {
+                    #( #match_arms )*
+                }
+            }
+        ))
+    }
+
     /// Expands the match arms of either the `union_fn::Call` or `union_fn::CallWithContext` impl.
     fn expand_union_fn_enum_call_impl_arms(&self) -> impl Iterator<Item = TokenStream2> + '_ {
         let ctx_param = self
@@ -283,36 +2051,113 @@ impl UnionFn {
             let method_span = method.span();
             let method_ident = method.ident();
             let variant_ident = method_ident.to_upper_camel_case();
+            let method_cfgs = method.cfg_attrs();
             let bindings = method.input_bindings(&self.state);
-            quote_spanned!(method_span=>
-                Self::#variant_ident { #( #bindings ),* } => {
+            if self.config().no_opt().is_some() {
+                return self.expand_union_fn_enum_call_inline_arm(&method);
+            }
+            let ctx_param = ctx_param.as_ref().filter(|_| method.no_context().is_none());
+            let impls_call = make_unsafe_block(
+                self.unsafety(),
+                quote_spanned!(method_span=>
                     <Self as ::union_fn::IntoOpt>::Impls::#method_ident(
                         #ctx_param #( #bindings ),*
                     )
+                ),
+            );
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                Self::#variant_ident { #( #bindings ),* } => {
+                    #impls_call
                 }
             )
         })
     }
 
+    /// Expands the match arm of the `union_fn::Call` or `union_fn::CallWithContext` impl
+    /// that directly contains the implementation of the `method`.
+    ///
+    /// # Note
+    ///
+    /// This is used if the generation of the call optimized type is skipped
+    /// and thus there are no `Impls` functions to delegate to.
+    fn expand_union_fn_enum_call_inline_arm(&self, method: &UnionFnMethod) -> TokenStream2 {
+        let method_span = method.span();
+        let variant_ident = method.ident().to_upper_camel_case();
+        let method_cfgs = method.cfg_attrs();
+        let fields = method
+            .inputs(&self.state)
+            .zip(method.input_bindings(&self.state))
+            .map(|(input, binding)| {
+                let pat = &input.pat;
+                match pat.as_ref() {
+                    syn::Pat::Ident(pat_ident) if pat_ident.ident == binding => {
+                        quote_spanned!(pat.span()=> #pat)
+                    }
+                    _ => quote_spanned!(pat.span()=> #binding: #pat),
+                }
+            });
+        let ctx_mutability = method.context_mutability(&self.state);
+        let ctx_binding = method.context(&self.state).and_then(|pat| match pat {
+            syn::Pat::Ident(pat_ident) if pat_ident.ident == "ctx" && ctx_mutability.is_some() => {
+                None
+            }
+            _ => match ctx_mutability {
+                Some(_) => Some(quote_spanned!(pat.span()=> let #pat = ctx;)),
+                None => Some(quote_spanned!(pat.span()=> let #pat = &*ctx;)),
+            },
+        });
+        let impl_block = make_unsafe_block(self.unsafety(), method.impl_block().to_token_stream());
+        quote_spanned!(method_span=>
+            #( #method_cfgs )*
+            Self::#variant_ident { #( #fields ),* } => {
+                #ctx_binding
+                #impl_block
+            }
+        )
+    }
+
     /// Expand the `#[union_fn]` constructors.
     fn expand_constructors(&self) -> TokenStream2 {
         let trait_span = self.span();
-        let trait_ident = self.ident();
         let ident_opt = self.ident_opt();
+        let ident_delegate = self.ident_delegate();
         let constructors = self.methods().map(|method| {
             let method_span = method.span();
+            let constness = self.constness();
             let method_ident = method.ident();
-            let method_attrs = method.attrs();
-            let params = method.ident_inputs(&self.state);
+            let method_attrs = method.constructor_attrs();
+            let must_use = Self::expand_constructor_must_use(&method);
+            let (params, destructure) = self.expand_constructor_params(&method);
             let param_bindings = method.input_bindings(&self.state);
+            let default_constructor = self.expand_default_constructor(&method);
+            let discriminant = cfg!(feature = "checked_dispatch").then(|| {
+                let discriminant = self.expand_discriminant(&method);
+                quote_spanned!(method_span=>
+                    discriminant: #discriminant,
+                )
+            });
+            let track_caller = Self::expand_track_caller(method_span);
+            let location = cfg!(feature = "source_locations").then(|| {
+                quote_spanned!(method_span=>
+                    location: ::core::panic::Location::caller(),
+                )
+            });
             quote_spanned!(method_span=>
                 #( #method_attrs )*
-                pub fn #method_ident( #( #params ),* ) -> Self {
+                #must_use
+                #track_caller
+                pub #constness fn #method_ident( #params ) -> Self {
+                    #destructure
                     Self {
-                        handler: <#trait_ident as ::union_fn::IntoOpt>::Delegator::#method_ident,
-                        args: <#trait_ident as ::union_fn::UnionFn>::Args::#method_ident( #( #param_bindings ),* ),
+                        handler: #ident_delegate::#method_ident,
+                        args: <#ident_opt as ::union_fn::UnionFn>::Args::#method_ident( #( #param_bindings ),* ),
+                        #discriminant
+                        #location
                     }
                 }
+
+                #default_constructor
             )
         });
         quote_spanned!(trait_span=>
@@ -325,25 +2170,40 @@ impl UnionFn {
     /// Expands the trait impl of either `union_fn::Call` or `union_fn::CallWithContext`.
     fn expand_call_impl(&self) -> TokenStream2 {
         let span = self.span();
-        let ident = self.ident();
         let ident_opt = self.ident_opt();
+        let unsafety = self.unsafety();
+        let handler_unsafety = self.handler_unsafety();
+        let call_trait = self.expand_call_trait(span);
+        let discriminant =
+            cfg!(feature = "checked_dispatch").then(|| quote_spanned!(span=> , self.discriminant));
         match self.state.get_context() {
             Some(context) => {
+                let handler_call = make_unsafe_block(
+                    handler_unsafety,
+                    quote_spanned!(span=> (self.handler)(ctx, &self.args #discriminant)),
+                );
+                let where_clause = self.expand_context_where_clause();
                 quote_spanned!(span=>
-                    impl ::union_fn::CallWithContext for #ident_opt {
+                    impl #call_trait for #ident_opt #where_clause {
                         type Context = #context;
 
-                        fn call(self, ctx: &mut Self::Context) -> <#ident as ::union_fn::UnionFn>::Output {
-                            (self.handler)(ctx, &self.args)
+                        #[inline]
+                        #unsafety fn call(self, ctx: &mut Self::Context) -> <#ident_opt as ::union_fn::UnionFn>::Output {
+                            #handler_call
                         }
                     }
                 )
             }
             None => {
+                let handler_call = make_unsafe_block(
+                    handler_unsafety,
+                    quote_spanned!(span=> (self.handler)(&self.args #discriminant)),
+                );
                 quote_spanned!(span=>
-                    impl ::union_fn::Call for #ident_opt {
-                        fn call(self) -> <#ident as ::union_fn::UnionFn>::Output {
-                            (self.handler)(&self.args)
+                    impl #call_trait for #ident_opt {
+                        #[inline]
+                        #unsafety fn call(self) -> <#ident_opt as ::union_fn::UnionFn>::Output {
+                            #handler_call
                         }
                     }
                 )
@@ -355,20 +2215,125 @@ impl UnionFn {
     fn expand_union_fn_args(&self) -> TokenStream2 {
         let trait_span = self.span();
         let trait_ident = self.ident();
-        let args_docs =
-            format!("Efficiently packed method arguments for the [`{trait_ident}`] type.");
+        let args_docs = match self.estimate_args_size() {
+            Some(size) => format!(
+                "Efficiently packed method arguments for the [`{trait_ident}`] type.\n\n\
+                 This union has an estimated maximum size of {size} bytes \
+                 (size of the largest variant's args). The exact size is given by [`Self::SIZE`]."
+            ),
+            None => format!(
+                "Efficiently packed method arguments for the [`{trait_ident}`] type.\n\n\
+                 The exact size of this union is given by [`Self::SIZE`]."
+            ),
+        };
         let ident_args = self.ident_args();
         let variants = self.expand_union_args_variants();
         let constructors = self.expand_union_args_constructors();
+        let accessors = self.expand_union_args_accessors();
+        let debug = self.expand_union_args_debug();
+        let repr = match (self.config().args_repr(), self.config().ffi()) {
+            (Some(repr), _) => Some(quote_spanned!(trait_span=> #[repr(#repr)])),
+            (None, Some(_)) => Some(quote_spanned!(trait_span=> #[repr(C)])),
+            (None, None) => None,
+        };
+        let align = self
+            .config()
+            .args_align()
+            .map(|align| quote_spanned!(trait_span=> #[repr(align(#align))]));
         quote_spanned!(trait_span =>
             #[doc = #args_docs]
             #[derive(core::marker::Copy, core::clone::Clone)]
+            #repr
+            #align
             pub union #ident_args {
                 #( #variants ),*
             }
 
             impl #ident_args {
+                /// The size of this union in bytes.
+                pub const SIZE: ::core::primitive::usize = ::core::mem::size_of::<Self>();
+
                 #( #constructors )*
+                #( #accessors )*
+                #debug
+            }
+        )
+    }
+
+    /// Returns the estimated size of the `#[union_fn]` arguments `union` in bytes if known.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the size of any method parameter type cannot be estimated.
+    fn estimate_args_size(&self) -> Option<usize> {
+        let (size, align) = self
+            .methods()
+            .map(|method| estimate_tuple_layout(method.input_types(&self.state)))
+            .try_fold((0, 1), |(size, align), layout| {
+                let (variant_size, variant_align) = layout?;
+                Some((
+                    usize::max(size, variant_size),
+                    usize::max(align, variant_align),
+                ))
+            })?;
+        let align = match self.config().args_align() {
+            Some(args_align) => usize::max(align, args_align.base10_parse().ok()?),
+            None => align,
+        };
+        Some(round_up(size, align))
+    }
+
+    /// Expands the `debug_with_discriminant` method of the `#[union_fn]` arguments `union`.
+    ///
+    /// # Note
+    ///
+    /// Parameters with types that do not implement `Debug` are formatted as `..`.
+    fn expand_union_args_debug(&self) -> TokenStream2 {
+        let trait_span = self.span();
+        let match_arms = self.methods().map(|method| {
+            let method_span = method.span();
+            let method_ident = method.ident();
+            let method_name = method.display_name();
+            let method_cfgs = method.cfg_attrs();
+            let discriminant = self.expand_discriminant(&method);
+            let bindings = method.input_bindings(&self.state);
+            let tuple_bindings = make_tuple_type(method_span, &bindings);
+            quote_spanned!(method_span=>
+                #( #method_cfgs )*
+                #discriminant => {
+                    let #tuple_bindings = unsafe { self.#method_ident };
+                    f.debug_tuple(#method_name)
+                        #(
+                            .field(&::union_fn::private::DebugFn(
+                                |f: &mut ::core::fmt::Formatter| {
+                                    (&::union_fn::private::FmtWrap(&#bindings)).debug_fmt(f)
+                                }
+                            ))
+                        )*
+                        .finish()
+                }
+            )
+        });
+        quote_spanned!(trait_span=>
+            /// Returns a debug formatter for the arguments of the `#[union_fn]` method with the `discriminant`.
+            ///
+            /// Arguments with types that do not implement `Debug` are formatted as `..`.
+            ///
+            /// # Safety
+            ///
+            /// The `discriminant` must refer to the `#[union_fn]` method whose arguments are stored in `self`.
+            pub unsafe fn debug_with_discriminant(
+                &self,
+                discriminant: ::core::primitive::usize,
+            ) -> impl ::core::fmt::Debug + '_ {
+                #[allow(unused_imports)]
+                use ::union_fn::private::{DebugFallback as _, DebugViaImpl as _};
+                ::union_fn::private::DebugFn(move |f: &mut ::core::fmt::Formatter| {
+                    match discriminant {
+                        #( #match_arms )*
+                        _ => f.write_str("<invalid discriminant>"),
+                    }
+                })
             }
         )
     }
@@ -377,13 +2342,17 @@ impl UnionFn {
     fn expand_union_args_variants(&self) -> impl Iterator<Item = TokenStream2> + '_ {
         self.methods().map(|method| {
             let method_span = method.span();
+            let internal_vis = self.expand_internal_vis(method_span);
             let method_ident = method.ident();
             let method_docs = method.doc_attrs();
+            let method_cfgs = method.cfg_attrs();
             let params = method.input_types(&self.state);
             let tuple_params = make_tuple_type(method_span, params);
             quote_spanned!(method_span =>
+                #( #method_cfgs )*
                 #( #method_docs )*
-                #method_ident: #tuple_params
+                #[allow(clippy::type_complexity)]
+                #internal_vis #method_ident: #tuple_params
             )
         })
     }
@@ -392,17 +2361,53 @@ impl UnionFn {
     fn expand_union_args_constructors(&self) -> impl Iterator<Item = TokenStream2> + '_ {
         self.methods().map(|method| {
             let method_span = method.span();
+            let constness = self.constness();
             let method_ident = method.ident();
-            let method_attrs = method.attrs();
+            let method_attrs = method.internal_attrs();
             let params = method.ident_inputs(&self.state);
             let param_bindings = method.input_bindings(&self.state);
             let tuple_bindings = make_tuple_type(method_span, param_bindings);
             quote_spanned!(method_span=>
                 #( #method_attrs )*
-                pub fn #method_ident( #( #params ),* ) -> Self {
+                pub #constness fn #method_ident( #( #params ),* ) -> Self {
                     Self { #method_ident: #tuple_bindings }
                 }
             )
         })
     }
+
+    /// Expands the `args_as_{method}` accessors of the `#[union_fn]` union variants.
+    ///
+    /// # Note
+    ///
+    /// Methods without parameters have nothing to access and thus are skipped.
+    fn expand_union_args_accessors(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        self.methods()
+            .filter(|method| method.inputs(&self.state).next().is_some())
+            .map(|method| {
+                let method_span = method.span();
+                let method_ident = method.ident();
+                let method_cfgs = method.cfg_attrs();
+                let accessor_ident = format_ident!("args_as_{}", method_ident);
+                let params = method.input_types(&self.state);
+                let tuple_params = make_tuple_type(method_span, params);
+                let accessor_docs =
+                    format!("Returns the arguments of the `{method_ident}` method.");
+                let safety_docs = format!(
+                    "The arguments stored in `self` must belong to the `{method_ident}` method."
+                );
+                quote_spanned!(method_span=>
+                    #( #method_cfgs )*
+                    #[doc = #accessor_docs]
+                    #[doc = ""]
+                    #[doc = "# Safety"]
+                    #[doc = ""]
+                    #[doc = #safety_docs]
+                    #[allow(clippy::type_complexity)]
+                    pub unsafe fn #accessor_ident(&self) -> #tuple_params {
+                        unsafe { self.#method_ident }
+                    }
+                )
+            })
+    }
 }
@@ -1,13 +1,18 @@
+#![cfg_attr(proc_macro_diagnostic, feature(proc_macro_diagnostic))]
+
 use analyse::UnionFnState;
+use config::UnionFnConfig;
 use method::UnionFnMethod;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::format_ident;
 use syn::spanned::Spanned;
+use utils::IdentExt as _;
 
 #[macro_use]
 mod error;
 mod analyse;
+mod config;
 mod expand;
 mod method;
 mod utils;
@@ -48,11 +53,149 @@ mod utils;
 /// [`Call::call`] or [`CallWithContext::call`] trait method depending on if the
 /// trait defines an [`type Output`] associated type.
 ///
+/// If all methods of the trait are `const fn` the generated functions are `const fn` as well
+/// and the `enum` additionally provides an inherent `const fn call` method that can be used
+/// in `const` contexts.
+///
+/// If the trait defines a `Context` type its methods take either `&mut Self::Context` or
+/// `&Self::Context` as first argument. Methods that only read the context can use the latter
+/// while the generated `call` methods still take the context by `&mut` for all methods.
+/// Methods marked with `#[no_context]` do not take the context parameter at all, e.g. a pure
+/// `fn multiply(a: i32, b: i32) -> i32`, while they are still called with the context.
+///
+/// Attributes such as `#[cold]` or `#[inline(always)]` on trait methods are forwarded to the
+/// generated functions implementing and delegating to the method but `#[cold]` is not applied
+/// to its constructors. Together with the `#[inline]` generated `call` methods this allows hot
+/// methods to be inlined throughout the whole dispatch chain of the `enum`. Lint attributes
+/// such as `#[allow(clippy::too_many_arguments)]` are forwarded the same way.
+///
+/// Methods marked with `#[union_fn(display_name = "i64.add")]` use `i64.add` instead of their
+/// identifier in `variant_name`, the variant name tables as well as for formatting and parsing.
+///
+/// Methods marked with `#[named_args]` generate a `pub struct` named after the method, e.g.
+/// `FillArgs` for `fn fill`, with a public field per parameter. Their constructors then take
+/// this struct instead of positional arguments, e.g. `Foo::fill(FillArgs { offset: 0, count: 1 })`,
+/// while the variants of the `enum` keep their named fields.
+///
+/// Parameters marked with `#[default(value)]` generate an additional constructor named after
+/// the method with a `_default` suffix that only takes the remaining parameters, e.g.
+/// `Foo::bump_by_default()` for `fn bump_by(value: &mut Self::Context, #[default(1)] by: i64)`.
+/// Rust does not support `by: i64 = 1` parameter syntax which is why an attribute is used instead.
+///
+/// If the trait has a method without parameters the `enum` implements [`Default`] returning
+/// the first such method in declaration order that is not conditionally compiled via `#[cfg]`,
+/// also if `stable_discriminants` is set.
+///
+/// The `enum` also implements [`Display`] formatting its instructions as `method_name(arg1, arg2)`
+/// where arguments with types that do not implement [`Display`] are formatted as `..`.
+/// Its [`FromStr`] impl parses this format back where arguments with types that do not
+/// implement [`FromStr`] always fail to parse. Commas within brackets or double quotes do not
+/// separate arguments while arguments whose textual representation contains other commas cannot
+/// be parsed back.
+///
+/// If all methods of the trait are `async fn` the `enum` implements the [`AsyncCall`] or
+/// [`AsyncCallWithContext`] traits instead whose `call_async` method returns a future. This
+/// requires the `async` crate feature and `no_opt` since the call optimized type cannot
+/// dispatch to futures of different types.
+///
+/// With the `source_locations` crate feature the constructors of the call optimized type and
+/// its conversion from the `enum` are `#[track_caller]` and store the source location of their
+/// caller which is returned by its `location` method, e.g. to report where a faulty instruction
+/// has been created. This is meant for debugging since it increases the size of the type.
+///
+/// With the `call_counts` crate feature every method has a `static` atomic counter which is
+/// incremented whenever the call optimized type dispatches to it. The `call_counts` method of
+/// the user facing type returns all counts, e.g. `[("add", 1000000), ("div_s", 12)]`, which
+/// allows to profile instruction usage without further setup. Requires the call optimized type.
+///
+/// The user facing type provides the number of methods via its `VARIANT_COUNT` constant.
+///
+/// Associated constants of the trait must have a value and are forwarded to the `enum` as
+/// `pub const` items so that methods can use them via `Self`, e.g. `Self::MAX_DEPTH`.
+///
+/// If all methods of the trait are `unsafe fn` the generated types implement the
+/// [`UnsafeCall`] or [`UnsafeCallWithContext`] traits instead whose `call` method is `unsafe`.
+///
+/// If all methods of the trait are `extern fn`, e.g. `extern "C" fn`, the generated method
+/// implementations and the handlers of the call optimized type use the same ABI.
+///
 /// [`IntoOpt::into_opt`]: trait.IntoOpt.html
+/// [`Display`]: core::fmt::Display
+/// [`FromStr`]: core::str::FromStr
 /// [`Call::call`]: trait.Call.html
 /// [`CallWithContext::call`]: trait.CallWithContext.html
+/// [`UnsafeCall`]: trait.UnsafeCall.html
+/// [`UnsafeCallWithContext`]: trait.UnsafeCallWithContext.html
+/// [`AsyncCall`]: trait.AsyncCall.html
+/// [`AsyncCallWithContext`]: trait.AsyncCallWithContext.html
 /// [`type Output`]: trait.UnionFn.html#associatedtype.Output
 ///
+/// ## Arguments
+///
+/// The `#[union_fn]` macro optionally accepts the following arguments:
+///
+/// - `enum_repr = "u8"`: Applies `#[repr(u8)]` to the generated enum.
+///     - Supports all primitive integer types.
+///     - Results in an error if there are more methods than the integer type can represent.
+/// - `args_repr = "C"`: Applies `#[repr(C)]` to the generated arguments `union`.
+/// - `args_align = 16`: Applies `#[repr(align(16))]` to the generated arguments `union`.
+///     - The alignment must be a power of two.
+/// - `max_args_size = 16`: Asserts at compile time that the generated arguments `union` is at most 16 bytes.
+///     - Guards against methods with large parameters that silently degrade data locality.
+/// - `warn_args_size = 32`: Emits a warning if the generated arguments `union` exceeds 32 bytes.
+///     - Defaults to 64 bytes if not specified.
+///     - The warning is emitted as a deprecation warning since proc. macros cannot emit warnings on stable Rust.
+///     - On nightly Rust with `--cfg proc_macro_diagnostic` the warning is emitted as a regular warning instead.
+/// - `max_variants = 256`: Asserts at compile time that the trait has at most 256 methods.
+///     - Methods disabled via `#[cfg]` are not counted.
+///     - Guards instruction sets that encode their discriminant in a fixed number of bits.
+/// - `opt_name = "CompiledFoo"`: Names the call optimized type `CompiledFoo` instead of `FooOpt`.
+/// - `args_name = "FooParams"`: Names the arguments `union` `FooParams` instead of `FooArgs`.
+/// - `delegate_name = "FooDispatch"`: Names the delegate type `FooDispatch` instead of `FooDelegate`.
+/// - `prefix = "__"`: Prefixes the default names of the internal types, e.g. `__FooOpt` instead of `FooOpt`.
+///     - Avoids name collisions with user defined types in scope, e.g. an existing `FooOpt`.
+///     - Applies to the call optimized type, the arguments `union`, the delegate and the impls types.
+///     - Custom names given via `opt_name`, `args_name` or `delegate_name` are not prefixed.
+/// - `module = "foo_impl"`: Generates the internal types into `pub mod foo_impl` instead of hiding them.
+///     - Allows to name the internal types, e.g. `foo_impl::FooOpt`, and to implement traits for them.
+///     - The delegate and impls types are still `#[doc(hidden)]` since they are pure implementation details.
+/// - `no_enum`: Skips the generation of the enum.
+///     - The call optimized type takes its place and is accessed via its name instead.
+///     - Cannot be used together with `enum_repr`, `non_exhaustive`, `visitor`, `binary`, `serde` or `arbitrary`.
+/// - `no_opt`: Skips the generation of the call optimized type and its arguments `union`.
+///     - The enum's `call` method then directly contains the implementations of all methods.
+///     - Allows parameters of well known non-`Copy` types such as `String` in which case the enum only implements `Clone`.
+///     - Cannot be used together with `no_enum`, `args_repr`, `args_align`, `max_args_size`, `warn_args_size` or any of the custom names.
+/// - `non_exhaustive`: Applies `#[non_exhaustive]` to the generated enum.
+///     - Allows to add new methods without breaking downstream crates matching on the enum.
+/// - `visitor`: Generates the `FooVisitor` trait with a `visit_foo` method per trait method.
+///     - The enum's `visit` method dispatches to the visitor method of its variant.
+///     - Adding a method to the trait requires all visitors to handle it.
+/// - `binary`: Implements `BinaryEncode` and `BinaryDecode` for the enum.
+///     - Requires the `binary` crate feature and all parameter types to implement `bytemuck::Pod`.
+///     - Encodes the discriminant as a single byte followed by the raw bytes of all parameters.
+///     - Supports at most 256 methods.
+/// - `serde`: Derives `Serialize` and `Deserialize` for the enum.
+///     - Requires the `serde` crate feature and all parameter types to implement `Serialize` and `Deserialize`.
+///     - Variants are named after the methods, e.g. `bump_by`.
+/// - `arbitrary`: Implements `arbitrary::Arbitrary` for the enum, e.g. for fuzz testing.
+///     - Requires the `arbitrary` crate feature and all parameter types to implement `Arbitrary`.
+///     - Selects one of the methods with equal probability.
+/// - `ffi`: Applies `#[repr(C)]` to the call optimized type and its arguments `union`.
+///     - The handlers then are `unsafe extern "C" fn` receiving the context and arguments as raw pointers.
+///     - Rejects a context or parameter types without a C compatible layout at compile time.
+///     - Allows to store the call optimized type in C structures and to call its handler from C code.
+///     - Cannot be used together with `no_opt`.
+/// - `context_bounds = "Send + Sync"`: Requires the context type to satisfy `Send + Sync`.
+///     - Adds `where Context: Send + Sync` to the generated call trait impls.
+///     - Rejects context types that do not satisfy the bounds at compile time.
+///     - Requires the trait to have a `Context` type.
+/// - `stable_discriminants`: Assigns the discriminants in alphabetical order of the method names.
+///     - By default discriminants are assigned in declaration order of the methods.
+///     - Keeps discriminants stable when methods are reordered, e.g. for binary encoded instructions.
+///     - Also orders the enum variants, the reflection tables, `zero_arg_variants` and `call_counts` alphabetically.
+///     - The `Default` impl still returns the first method without parameters in declaration order.
+///
 /// ## Example
 ///
 /// ### Interpreters
@@ -341,6 +484,8 @@ struct UnionFn {
     item: syn::ItemTrait,
     /// Extraneous state required for analysis and expansion.
     state: UnionFnState,
+    /// The identifiers of the methods in the order in which they are declared.
+    declaration_order: Vec<syn::Ident>,
 }
 
 impl UnionFn {
@@ -359,28 +504,76 @@ impl UnionFn {
         &self.item.ident
     }
 
+    /// Returns the prefix of the identifiers of the generated internal types.
+    ///
+    /// This is empty unless the `prefix` macro argument has been provided.
+    fn prefix(&self) -> String {
+        self.config()
+            .prefix()
+            .map(syn::LitStr::value)
+            .unwrap_or_default()
+    }
+
     /// Returns the identifier for the call optimized `#[union_fn]` type.
     pub fn ident_opt(&self) -> syn::Ident {
-        format_ident!("{}Opt", self.ident())
+        match self.config().opt_name() {
+            Some(opt_name) => opt_name.clone(),
+            None => format_ident!("{}{}Opt", self.prefix(), self.ident()),
+        }
     }
 
     /// Returns the identifier for the args union `#[union_fn]` type.
     pub fn ident_args(&self) -> syn::Ident {
-        format_ident!("{}Args", self.ident())
+        match self.config().args_name() {
+            Some(args_name) => args_name.clone(),
+            None => format_ident!("{}{}Args", self.prefix(), self.ident()),
+        }
+    }
+
+    /// Returns the identifier for the visitor `#[union_fn]` trait.
+    pub fn ident_visitor(&self) -> syn::Ident {
+        format_ident!("{}Visitor", self.ident())
+    }
+
+    /// Returns the identifier for the `static` array of method names, e.g. `COUNTER_VARIANT_NAMES`.
+    pub fn ident_variant_names(&self) -> syn::Ident {
+        format_ident!("{}_VARIANT_NAMES", self.ident().to_shouty_snake_case())
+    }
+
+    /// Returns the identifier for the `static` array of argument sizes, e.g. `COUNTER_VARIANT_ARG_SIZES`.
+    pub fn ident_variant_arg_sizes(&self) -> syn::Ident {
+        format_ident!("{}_VARIANT_ARG_SIZES", self.ident().to_shouty_snake_case())
+    }
+
+    /// Returns the identifier for the hidden module assigning the discriminants, e.g. `__CounterDiscriminants`.
+    pub fn ident_discriminants(&self) -> syn::Ident {
+        format_ident!("__{}Discriminants", self.ident())
+    }
+
+    /// Returns the identifier for the `static` call counter of the `method`, e.g. `COUNTER_BUMP_BY_CALL_COUNT`.
+    pub fn ident_call_count(&self, method: &UnionFnMethod) -> syn::Ident {
+        format_ident!(
+            "{}_{}_CALL_COUNT",
+            self.ident().to_shouty_snake_case(),
+            method.ident().to_shouty_snake_case(),
+        )
     }
 
     /// Returns the identifier for the impls `#[union_fn]` type.
     pub fn ident_impls(&self) -> syn::Ident {
-        format_ident!("{}Impls", self.ident())
+        format_ident!("{}{}Impls", self.prefix(), self.ident())
     }
 
     /// Returns the identifier for the delegate `#[union_fn]` type.
     pub fn ident_delegate(&self) -> syn::Ident {
-        format_ident!("{}Delegate", self.ident())
+        match self.config().delegate_name() {
+            Some(delegate_name) => delegate_name.clone(),
+            None => format_ident!("{}{}Delegate", self.prefix(), self.ident()),
+        }
     }
 
     /// Returns an iterator over the `#[union_fn]` methods.
-    pub fn methods(&self) -> impl Iterator<Item = UnionFnMethod> {
+    pub fn methods(&self) -> impl Iterator<Item = UnionFnMethod<'_>> {
         self.item
             .items
             .iter()
@@ -391,8 +584,88 @@ impl UnionFn {
             .map(UnionFnMethod::from)
     }
 
+    /// Returns an iterator over the `#[union_fn]` methods in declaration order.
+    ///
+    /// # Note
+    ///
+    /// This differs from [`UnionFn::methods`] if the `stable_discriminants` macro argument is set.
+    pub fn declared_methods(&self) -> impl Iterator<Item = UnionFnMethod<'_>> {
+        self.declaration_order
+            .iter()
+            .filter_map(|ident| self.methods().find(|method| method.ident() == ident))
+    }
+
+    /// Returns the first method parameter type that is known to not implement `Copy` if any.
+    pub fn non_copy_input(&self) -> Option<&syn::Type> {
+        self.methods()
+            .flat_map(|method| method.input_types(&self.state))
+            .find(|ty| utils::is_known_non_copy(ty))
+    }
+
+    /// Returns `true` if the generated enum can implement `Copy`.
+    pub fn is_copy(&self) -> bool {
+        self.non_copy_input().is_none()
+    }
+
+    /// Returns an iterator over the associated constants of the `#[union_fn]` trait.
+    pub fn consts(&self) -> impl Iterator<Item = &syn::TraitItemConst> {
+        self.item.items.iter().filter_map(|item| match item {
+            syn::TraitItem::Const(item) => Some(item),
+            _ => None,
+        })
+    }
+
     /// Expand to the `#[union_fn]` `Output` type if any or `()`.
     pub fn output_type(&self) -> syn::Type {
         self.state.get_output_type(self.span())
     }
+
+    /// Returns the `const` token if all `#[union_fn]` methods are `const fn`.
+    pub fn constness(&self) -> Option<syn::token::Const> {
+        self.state.get_constness()
+    }
+
+    /// Returns the `unsafe` token if all `#[union_fn]` methods are `unsafe fn`.
+    pub fn unsafety(&self) -> Option<syn::token::Unsafe> {
+        self.state.get_unsafety()
+    }
+
+    /// Returns the `unsafe` token if the handlers of the call optimized type are `unsafe fn`.
+    ///
+    /// # Note
+    ///
+    /// Handlers are always `unsafe fn` with the `ffi` argument since they then receive raw pointers.
+    pub fn handler_unsafety(&self) -> Option<syn::token::Unsafe> {
+        match self.config().ffi() {
+            Some(ffi) => Some(syn::token::Unsafe(ffi.span())),
+            None => self.unsafety(),
+        }
+    }
+
+    /// Returns the ABI if all `#[union_fn]` methods are `extern fn`, e.g. `extern "C" fn`.
+    pub fn abi(&self) -> Option<syn::Abi> {
+        self.state.get_abi()
+    }
+
+    /// Returns the ABI of the handlers of the call optimized type if any.
+    ///
+    /// # Note
+    ///
+    /// Handlers are always `extern "C" fn` with the `ffi` argument.
+    pub fn handler_abi(&self) -> Option<syn::Abi> {
+        match self.config().ffi() {
+            Some(ffi) => Some(syn::parse_quote_spanned!(ffi.span()=> extern "C")),
+            None => self.abi(),
+        }
+    }
+
+    /// Returns the `async` token if all `#[union_fn]` methods are `async fn`.
+    pub fn asyncness(&self) -> Option<syn::token::Async> {
+        self.state.get_asyncness()
+    }
+
+    /// Returns the configuration given via the `#[union_fn]` macro arguments.
+    pub fn config(&self) -> &UnionFnConfig {
+        self.state.config()
+    }
 }
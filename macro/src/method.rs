@@ -1,4 +1,7 @@
-use crate::{analyse::UnionFnState, utils::AttributeExt};
+use crate::{
+    analyse::UnionFnState,
+    utils::{AttributeExt, IdentExt as _},
+};
 use proc_macro2::Span;
 use quote::format_ident;
 use syn::spanned::Spanned as _;
@@ -28,9 +31,30 @@ impl<'a> UnionFnMethod<'a> {
         &self.item.sig.ident
     }
 
-    /// Returns the attributes of the method.
-    pub fn attrs(&self) -> &[syn::Attribute] {
-        &self.item.attrs
+    /// Returns an iterator yielding the attributes of the method for its user facing constructors.
+    ///
+    /// This filters out `#[cold]` attributes since those are only meaningful
+    /// for the functions implementing the method, not for its constructors.
+    pub fn constructor_attrs(&self) -> impl Iterator<Item = &'a syn::Attribute> + 'a {
+        self.item.attrs.iter().filter(|attr| {
+            !attr.is_cold_attribute()
+                && !attr.is_named_args_attribute()
+                && !attr.is_no_context_attribute()
+                && !attr.is_union_fn_attribute()
+        })
+    }
+
+    /// Returns an iterator yielding the attributes of the method for internally used items.
+    ///
+    /// This filters out `#[deprecated]` attributes since those are only
+    /// meaningful for the user facing constructors of the method.
+    pub fn internal_attrs(&self) -> impl Iterator<Item = &'a syn::Attribute> + 'a {
+        self.item.attrs.iter().filter(|attr| {
+            !attr.is_deprecated_attribute()
+                && !attr.is_named_args_attribute()
+                && !attr.is_no_context_attribute()
+                && !attr.is_union_fn_attribute()
+        })
     }
 
     /// Returns an iterator yielding the doc attributes of the method.
@@ -41,25 +65,185 @@ impl<'a> UnionFnMethod<'a> {
             .filter(|attr| attr.is_docs_attribute())
     }
 
+    /// Returns `true` if the method carries a `#[must_use]` attribute.
+    pub fn is_must_use(&self) -> bool {
+        self.item
+            .attrs
+            .iter()
+            .any(|attr| attr.is_must_use_attribute())
+    }
+
+    /// Returns the `#[named_args]` attribute of the method if any.
+    ///
+    /// Methods with this attribute take their arguments as a single named arguments struct.
+    pub fn named_args(&self) -> Option<&'a syn::Attribute> {
+        self.item
+            .attrs
+            .iter()
+            .find(|attr| attr.is_named_args_attribute())
+    }
+
+    /// Returns the `#[no_context]` attribute of the method if any.
+    ///
+    /// Methods with this attribute do not take the context parameter
+    /// even though the `#[union_fn]` trait defines a `Context` type.
+    pub fn no_context(&self) -> Option<&'a syn::Attribute> {
+        self.item
+            .attrs
+            .iter()
+            .find(|attr| attr.is_no_context_attribute())
+    }
+
+    /// Returns the name of the method used for formatting and parsing.
+    ///
+    /// This is the `display_name` of the `#[union_fn(display_name = "..")]`
+    /// attribute if any and the identifier of the method otherwise.
+    pub fn display_name(&self) -> String {
+        self.item
+            .attrs
+            .iter()
+            .filter(|attr| attr.is_union_fn_attribute())
+            .find_map(|attr| Self::parse_display_name(attr).ok())
+            .map(|display_name| display_name.value())
+            .unwrap_or_else(|| self.ident().to_string())
+    }
+
+    /// Parses the `display_name` of the `#[union_fn(display_name = "..")]` method attribute.
+    ///
+    /// # Errors
+    ///
+    /// If the attribute is malformed or has unsupported arguments.
+    pub fn parse_display_name(attr: &syn::Attribute) -> syn::Result<syn::LitStr> {
+        let list = match attr.parse_meta()? {
+            syn::Meta::List(list) => list,
+            meta => bail_spanned!(
+                meta,
+                "expected #[union_fn(display_name = \"..\")] method attribute"
+            ),
+        };
+        let mut display_name = None;
+        for nested in &list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                })) if path.is_ident("display_name") => {
+                    if display_name.is_some() {
+                        bail_spanned!(nested, "encountered duplicate argument for #[union_fn]")
+                    }
+                    display_name = Some(lit_str.clone());
+                }
+                _ => bail_spanned!(
+                    nested,
+                    "encountered unsupported argument for #[union_fn] method attribute"
+                ),
+            }
+        }
+        display_name.ok_or_else(|| {
+            format_err_spanned!(
+                attr,
+                "expected #[union_fn(display_name = \"..\")] method attribute"
+            )
+        })
+    }
+
+    /// Returns `true` if the method takes the context parameter.
+    pub fn has_context(&self, state: &UnionFnState) -> bool {
+        state.get_context().is_some() && self.no_context().is_none()
+    }
+
+    /// Returns the identifier of the named arguments struct of the method, e.g. `ComplexArgs`.
+    pub fn ident_named_args(&self) -> syn::Ident {
+        format_ident!("{}Args", self.ident().to_upper_camel_case())
+    }
+
+    /// Returns an iterator yielding the `#[cfg]` attributes of the method.
+    ///
+    /// These need to be forwarded to all code generated for the method.
+    pub fn cfg_attrs(&self) -> impl Iterator<Item = &'a syn::Attribute> + 'a {
+        self.item
+            .attrs
+            .iter()
+            .filter(|attr| attr.is_cfg_attribute())
+    }
+
     /// Returns the inputs of the method without the context parameter.
     ///
     /// This returns the inputs exactly as they are found in the proc macro invocation.
-    pub fn inputs(&self, state: &UnionFnState) -> impl Iterator<Item = &syn::PatType> + '_ {
-        let mut iter = self.item.sig.inputs.iter().filter_map(|item| match item {
+    pub fn inputs(&self, state: &UnionFnState) -> impl Iterator<Item = &'a syn::PatType> + 'a {
+        let mut iter = self.item.sig.inputs.iter().map(|item| match item {
             syn::FnArg::Receiver(receiver) => {
                 panic!("encountered invalid self receiver: {receiver:?}")
             }
-            syn::FnArg::Typed(pat_type) => Some(pat_type),
+            syn::FnArg::Typed(pat_type) => pat_type,
         });
-        if state.get_context().is_some() {
-            // If the trait has a context we need to pop the context argument.
+        if self.has_context(state) {
+            // If the method takes the context we need to pop the context argument.
             let _ = iter.next();
         }
         iter
     }
 
+    /// Returns the inputs of the method without the context parameter for its implementation.
+    ///
+    /// This strips the `#[default]` attributes of the inputs since those are
+    /// only meaningful for the generated constructors of the method.
+    pub fn impl_inputs(&self, state: &UnionFnState) -> Vec<syn::PatType> {
+        self.inputs(state)
+            .map(|pat_type| syn::PatType {
+                attrs: Self::strip_default_attrs(&pat_type.attrs),
+                ..pat_type.clone()
+            })
+            .collect()
+    }
+
+    /// Returns the default values of the inputs without the context parameter.
+    ///
+    /// Yields `None` for inputs without a `#[default(value)]` attribute.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[default]` attribute does not hold a single Rust expression.
+    pub fn input_defaults(&self, state: &UnionFnState) -> syn::Result<Vec<Option<syn::Expr>>> {
+        self.inputs(state)
+            .map(|pat_type| {
+                pat_type
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.is_default_attribute())
+                    .map(|attr| attr.parse_args::<syn::Expr>())
+                    .transpose()
+            })
+            .collect()
+    }
+
+    /// Returns `true` if any input of the method has a `#[default]` attribute.
+    pub fn has_input_defaults(&self, state: &UnionFnState) -> bool {
+        self.inputs(state).any(|pat_type| {
+            pat_type
+                .attrs
+                .iter()
+                .any(|attr| attr.is_default_attribute())
+        })
+    }
+
+    /// Returns the identifier of the constructor using the default values of the inputs, e.g. `bump_by_default`.
+    pub fn ident_default(&self) -> syn::Ident {
+        format_ident!("{}_default", self.ident())
+    }
+
+    /// Returns `attrs` without `#[default]` attributes.
+    fn strip_default_attrs(attrs: &[syn::Attribute]) -> Vec<syn::Attribute> {
+        attrs
+            .iter()
+            .filter(|attr| !attr.is_default_attribute())
+            .cloned()
+            .collect()
+    }
+
     /// Returns the input types of the method without the context parameter.
-    pub fn input_types(&self, state: &UnionFnState) -> Vec<&syn::Type> {
+    pub fn input_types(&self, state: &UnionFnState) -> Vec<&'a syn::Type> {
         self.inputs(state).map(|pat_type| &*pat_type.ty).collect()
     }
 
@@ -69,11 +253,34 @@ impl<'a> UnionFnMethod<'a> {
     ///
     /// This replaces the patterns of the inputs if they are not identifiers
     /// with artificial numbered identifiers in the form `_N`. This is required
-    /// for some proc. macro expansions.
+    /// for some proc. macro expansions. Tuple patterns of identifiers such as
+    /// `(x, y)` are bound as `x_y` instead unless this collides with another binding.
     pub fn input_bindings(&self, state: &UnionFnState) -> Vec<syn::Ident> {
-        self.inputs(state)
+        let pats = self
+            .inputs(state)
+            .map(|pat_type| &*pat_type.pat)
+            .collect::<Vec<_>>();
+        let idents = pats
+            .iter()
+            .map(|pat| Self::pattern_ident(pat))
+            .collect::<Vec<_>>();
+        pats.iter()
+            .zip(&idents)
             .enumerate()
-            .map(|(n, pat_type)| Self::ident_or_numbered(&pat_type.pat, n))
+            .map(|(n, (pat, ident))| {
+                let is_unique = |ident: &syn::Ident| {
+                    idents
+                        .iter()
+                        .enumerate()
+                        .all(|(m, other)| m == n || other.as_ref() != Some(ident))
+                };
+                match ident {
+                    Some(ident) if !matches!(pat, syn::Pat::Tuple(_)) || is_unique(ident) => {
+                        ident.clone()
+                    }
+                    _ => format_ident!("_{}", n),
+                }
+            })
             .collect()
     }
 
@@ -83,14 +290,14 @@ impl<'a> UnionFnMethod<'a> {
     ///
     /// This replaces the patterns of the inputs if they are not identifiers
     /// with artificial numbered identifiers in the form `_N`. This is required
-    /// for some proc. macro expansions.
+    /// for some proc. macro expansions. Tuple patterns are named the same as
+    /// in [`UnionFnMethod::input_bindings`].
     pub fn ident_inputs(&self, state: &UnionFnState) -> Vec<syn::PatType> {
         self.inputs(state)
-            .enumerate()
-            .map(|(n, pat_type)| {
-                let ident = Self::ident_or_numbered(&pat_type.pat, n);
+            .zip(self.input_bindings(state))
+            .map(|(pat_type, ident)| {
                 let pat = syn::parse_quote!(#ident);
-                let attrs = pat_type.attrs.clone();
+                let attrs = Self::strip_default_attrs(&pat_type.attrs);
                 let colon_token = pat_type.colon_token;
                 let ty = pat_type.ty.clone();
                 syn::PatType {
@@ -103,51 +310,85 @@ impl<'a> UnionFnMethod<'a> {
             .collect()
     }
 
-    /// Returns the identifier of the given pattern or generated a numbered dummy.
+    /// Returns the identifier equivalent to the given pattern if any.
     ///
-    /// Returns an identifier if the pattern is equivalent to an identifier
-    /// and otherwise returns an artificial numbered identifier in the form
-    /// `_N`.
-    fn ident_or_numbered(pat: &syn::Pat, n: usize) -> syn::Ident {
-        let make_numbered = || format_ident!("_{}", n);
+    /// # Note
+    ///
+    /// Tuple patterns such as `(x, y)` are named by joining the identifiers
+    /// of their elements with underscores, e.g. `x_y`.
+    fn pattern_ident(pat: &syn::Pat) -> Option<syn::Ident> {
         match pat {
             syn::Pat::Ident(pat_ident) => {
                 if pat_ident.subpat.is_some()
                     || pat_ident.by_ref.is_some()
                     || pat_ident.mutability.is_some()
                 {
-                    return make_numbered();
+                    return None;
                 }
-                pat_ident.ident.clone()
+                Some(pat_ident.ident.clone())
             }
             syn::Pat::Path(pat_path) => {
                 if pat_path.qself.is_some() {
-                    return make_numbered();
+                    return None;
                 }
-                if let Some(ident) = pat_path.path.get_ident() {
-                    return ident.clone();
+                pat_path.path.get_ident().cloned()
+            }
+            syn::Pat::Tuple(pat_tuple) => {
+                if pat_tuple.elems.is_empty() {
+                    return None;
                 }
-                make_numbered()
+                let idents = pat_tuple
+                    .elems
+                    .iter()
+                    .map(Self::pattern_ident)
+                    .collect::<Option<Vec<_>>>()?;
+                let joined = idents
+                    .iter()
+                    .map(syn::Ident::to_string)
+                    .collect::<Vec<_>>()
+                    .join("_");
+                Some(format_ident!("{}", joined, span = pat_tuple.span()))
             }
-            _ => make_numbered(),
+            _ => None,
         }
     }
 
     /// Returns the context parameter pattern of the method if any.
     ///
+    /// Returns `None` for methods marked with `#[no_context]`.
+    ///
     /// # Note
     ///
     /// We are not interested in the type since we previously asserted that
     /// it is always just a reference to the associated trait `&mut Self::Context`.
     pub fn context(&self, state: &UnionFnState) -> Option<&syn::Pat> {
-        state
-            .get_context()
-            .map(|_| &self.item.sig.inputs[0])
+        self.has_context(state)
+            .then(|| &self.item.sig.inputs[0])
+            .map(|arg| match arg {
+                syn::FnArg::Receiver(receiver) => {
+                    panic!("encountered invalid self receiver: {receiver:?}")
+                }
+                syn::FnArg::Typed(pat_type) => &*pat_type.pat,
+            })
+    }
+
+    /// Returns the `mut` token of the context parameter of the method if any.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the method has no context parameter or if it
+    /// only borrows the context immutably via `&Self::Context`.
+    pub fn context_mutability(&self, state: &UnionFnState) -> Option<syn::token::Mut> {
+        self.has_context(state)
+            .then(|| &self.item.sig.inputs[0])
             .and_then(|arg| match arg {
+                syn::FnArg::Typed(syn::PatType { ty, .. }) => match &**ty {
+                    syn::Type::Reference(reference) => reference.mutability,
+                    _ => None,
+                },
                 syn::FnArg::Receiver(receiver) => {
                     panic!("encountered invalid self receiver: {receiver:?}")
                 }
-                syn::FnArg::Typed(pat_type) => Some(&*pat_type.pat),
             })
     }
 
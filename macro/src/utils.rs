@@ -1,4 +1,4 @@
-use heck::AsUpperCamelCase;
+use heck::{AsShoutySnakeCase, AsUpperCamelCase};
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
@@ -8,13 +8,29 @@ pub trait AttributeExt {
     /// Returns `true` if the [`struct@syn::Attribute`] is a Rust documentation attribute.
     fn is_docs_attribute(&self) -> bool;
 
-    /// Returns `Some` if the [`struct@syn::Attribute`] is a Rust doc attribute.
-    fn filter_docs(&self) -> Option<&syn::Attribute>;
+    /// Returns `true` if the [`struct@syn::Attribute`] is a Rust conditional compilation attribute.
+    fn is_cfg_attribute(&self) -> bool;
 
-    /// Returns the contents of the [`struct@syn::Attribute`] if it is a Rust doc attribute
-    ///
-    /// Returns `None` otherwise.
-    fn get_docs(&self) -> Option<syn::LitStr>;
+    /// Returns `true` if the [`struct@syn::Attribute`] is a Rust deprecation attribute.
+    fn is_deprecated_attribute(&self) -> bool;
+
+    /// Returns `true` if the [`struct@syn::Attribute`] is a Rust `#[must_use]` attribute.
+    fn is_must_use_attribute(&self) -> bool;
+
+    /// Returns `true` if the [`struct@syn::Attribute`] is a Rust `#[cold]` attribute.
+    fn is_cold_attribute(&self) -> bool;
+
+    /// Returns `true` if the [`struct@syn::Attribute`] is a `#[union_fn]` `#[named_args]` attribute.
+    fn is_named_args_attribute(&self) -> bool;
+
+    /// Returns `true` if the [`struct@syn::Attribute`] is a `#[union_fn]` parameter `#[default]` attribute.
+    fn is_default_attribute(&self) -> bool;
+
+    /// Returns `true` if the [`struct@syn::Attribute`] is a `#[union_fn]` `#[no_context]` attribute.
+    fn is_no_context_attribute(&self) -> bool;
+
+    /// Returns `true` if the [`struct@syn::Attribute`] is a `#[union_fn(..)]` method attribute.
+    fn is_union_fn_attribute(&self) -> bool;
 }
 
 impl AttributeExt for syn::Attribute {
@@ -22,22 +38,36 @@ impl AttributeExt for syn::Attribute {
         self.path.is_ident("doc")
     }
 
-    fn filter_docs(&self) -> Option<&syn::Attribute> {
-        if self.is_docs_attribute() {
-            return Some(self);
-        }
-        None
+    fn is_cfg_attribute(&self) -> bool {
+        self.path.is_ident("cfg")
     }
 
-    fn get_docs(&self) -> Option<syn::LitStr> {
-        self.filter_docs()
-            .and_then(|attr| match attr.parse_meta().ok()? {
-                syn::Meta::NameValue(syn::MetaNameValue {
-                    lit: syn::Lit::Str(lit_str),
-                    ..
-                }) => Some(lit_str),
-                _ => None,
-            })
+    fn is_deprecated_attribute(&self) -> bool {
+        self.path.is_ident("deprecated")
+    }
+
+    fn is_must_use_attribute(&self) -> bool {
+        self.path.is_ident("must_use")
+    }
+
+    fn is_cold_attribute(&self) -> bool {
+        self.path.is_ident("cold")
+    }
+
+    fn is_named_args_attribute(&self) -> bool {
+        self.path.is_ident("named_args")
+    }
+
+    fn is_default_attribute(&self) -> bool {
+        self.path.is_ident("default")
+    }
+
+    fn is_no_context_attribute(&self) -> bool {
+        self.path.is_ident("no_context")
+    }
+
+    fn is_union_fn_attribute(&self) -> bool {
+        self.path.is_ident("union_fn")
     }
 }
 
@@ -45,12 +75,31 @@ impl AttributeExt for syn::Attribute {
 pub trait IdentExt {
     /// Converts the identifier to an upper camel case identifier.
     fn to_upper_camel_case(&self) -> syn::Ident;
+
+    /// Converts the identifier to a shouty snake case identifier.
+    fn to_shouty_snake_case(&self) -> syn::Ident;
 }
 
 impl IdentExt for syn::Ident {
     fn to_upper_camel_case(&self) -> syn::Ident {
         format_ident!("{}", AsUpperCamelCase(self.to_string()).to_string())
     }
+
+    fn to_shouty_snake_case(&self) -> syn::Ident {
+        format_ident!("{}", AsShoutySnakeCase(self.to_string()).to_string())
+    }
+}
+
+/// Wraps `expr` in an `unsafe` block if `unsafety` is `Some`.
+///
+/// # Note
+///
+/// Returns `expr` unchanged otherwise.
+pub fn make_unsafe_block(unsafety: Option<syn::token::Unsafe>, expr: TokenStream2) -> TokenStream2 {
+    match unsafety {
+        Some(unsafety) => quote_spanned!(unsafety.span=> #unsafety { #expr }),
+        None => expr,
+    }
 }
 
 /// Turns `args` into a Rust tuple type.
@@ -81,3 +130,105 @@ where
         }
     }
 }
+
+/// Returns `true` if `ty` is known to not implement `Copy`.
+///
+/// # Note
+///
+/// A proc. macro cannot query trait impls so this only detects `&mut T` and
+/// well known owning types of `std` such as `String`, `Vec<T>` or `Box<T>`.
+pub fn is_known_non_copy(ty: &syn::Type) -> bool {
+    const NON_COPY: &[&str] = &[
+        "String",
+        "Vec",
+        "Box",
+        "Rc",
+        "Arc",
+        "Cell",
+        "RefCell",
+        "VecDeque",
+        "LinkedList",
+        "BinaryHeap",
+        "HashMap",
+        "HashSet",
+        "BTreeMap",
+        "BTreeSet",
+        "CString",
+        "OsString",
+        "PathBuf",
+    ];
+    match ty {
+        syn::Type::Reference(ty) => ty.mutability.is_some(),
+        syn::Type::Array(ty) => is_known_non_copy(&ty.elem),
+        syn::Type::Group(ty) => is_known_non_copy(&ty.elem),
+        syn::Type::Paren(ty) => is_known_non_copy(&ty.elem),
+        syn::Type::Tuple(ty) => ty.elems.iter().any(is_known_non_copy),
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| NON_COPY.iter().any(|name| segment.ident == name))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Returns the estimated size and alignment of `ty` in bytes if known.
+///
+/// # Note
+///
+/// A proc. macro cannot query type layouts so this only handles primitive
+/// types with a target independent size as well as arrays and tuples of them.
+/// Tuples are assumed to be laid out without padding between their fields
+/// which holds since Rust reorders them and all sizes are multiples of their alignment.
+pub fn estimate_layout(ty: &syn::Type) -> Option<(usize, usize)> {
+    match ty {
+        syn::Type::Array(ty) => {
+            let len = match &ty.len {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(len),
+                    ..
+                }) => len.base10_parse::<usize>().ok()?,
+                _ => return None,
+            };
+            let (size, align) = estimate_layout(&ty.elem)?;
+            Some((size * len, align))
+        }
+        syn::Type::Group(ty) => estimate_layout(&ty.elem),
+        syn::Type::Paren(ty) => estimate_layout(&ty.elem),
+        syn::Type::Tuple(ty) => estimate_tuple_layout(ty.elems.iter()),
+        syn::Type::Path(ty) if ty.qself.is_none() => {
+            let size = match ty.path.segments.last()?.ident.to_string().as_str() {
+                "u8" | "i8" | "bool" => 1,
+                "u16" | "i16" => 2,
+                "u32" | "i32" | "f32" | "char" => 4,
+                "u64" | "i64" | "f64" => 8,
+                "u128" | "i128" => 16,
+                _ => return None,
+            };
+            Some((size, size))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the estimated size and alignment of a tuple of `elems` in bytes if known.
+///
+/// # Note
+///
+/// See [`estimate_layout`] for the limitations of the estimation.
+pub fn estimate_tuple_layout<'a, I>(elems: I) -> Option<(usize, usize)>
+where
+    I: IntoIterator<Item = &'a syn::Type>,
+{
+    let (size, align) = elems.into_iter().try_fold((0, 1), |(size, align), elem| {
+        let (elem_size, elem_align) = estimate_layout(elem)?;
+        Some((size + elem_size, usize::max(align, elem_align)))
+    })?;
+    Some((round_up(size, align), align))
+}
+
+/// Rounds `size` up to the next multiple of `align`.
+pub fn round_up(size: usize, align: usize) -> usize {
+    size.div_ceil(align) * align
+}
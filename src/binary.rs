@@ -0,0 +1,87 @@
+//! Binary encoding and decoding of `#[union_fn]` instruction sequences.
+
+use std::{
+    io::{self, Read, Write},
+    vec::Vec,
+};
+
+/// Allows `#[union_fn]` types to be encoded into their binary representation.
+///
+/// # Note
+///
+/// This trait is implemented by `#[union_fn(binary)]` expansions for the generated enum.
+/// The encoding is a single byte for the discriminant followed by the raw bytes of all
+/// parameters of the encoded method.
+pub trait BinaryEncode {
+    /// Encodes `self` into `buf`.
+    ///
+    /// # Errors
+    ///
+    /// If writing to `buf` fails.
+    fn encode_to(&self, buf: &mut impl Write) -> io::Result<()>;
+
+    /// Encodes `self` by appending it to `buf`.
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.encode_to(buf)
+            .expect("writing to a `Vec<u8>` never fails")
+    }
+}
+
+/// Allows `#[union_fn]` types to be decoded from their binary representation.
+///
+/// # Note
+///
+/// This trait is implemented by `#[union_fn(binary)]` expansions for the generated enum.
+pub trait BinaryDecode: Sized {
+    /// Decodes an instance from `buf`.
+    ///
+    /// # Errors
+    ///
+    /// - If reading from `buf` fails.
+    /// - If the decoded discriminant does not refer to any `#[union_fn]` method.
+    fn decode_from(buf: &mut impl Read) -> io::Result<Self>;
+
+    /// Decodes an instance from `buf` starting at `cursor`.
+    ///
+    /// Advances `cursor` past the decoded instance upon success.
+    /// Returns `None` and leaves `cursor` unchanged if decoding fails.
+    fn decode(buf: &[u8], cursor: &mut usize) -> Option<Self> {
+        let mut bytes = buf.get(*cursor..)?;
+        let decoded = Self::decode_from(&mut bytes).ok()?;
+        *cursor = buf.len() - bytes.len();
+        Some(decoded)
+    }
+}
+
+/// Encodes all `instrs` in sequence into `buf`.
+///
+/// # Errors
+///
+/// If writing to `buf` fails.
+pub fn encode_all<T>(instrs: &[T], buf: &mut impl Write) -> io::Result<()>
+where
+    T: BinaryEncode,
+{
+    instrs.iter().try_for_each(|instr| instr.encode_to(buf))
+}
+
+/// Decodes a sequence of instances from `buf` until it is exhausted.
+///
+/// # Errors
+///
+/// - If reading from `buf` fails.
+/// - If `buf` ends within an encoded instance.
+/// - If a decoded discriminant does not refer to any `#[union_fn]` method.
+pub fn decode_all<T>(buf: &mut impl Read) -> io::Result<Vec<T>>
+where
+    T: BinaryDecode,
+{
+    let mut bytes = Vec::new();
+    buf.read_to_end(&mut bytes)?;
+    let mut bytes = &bytes[..];
+    let mut instrs = Vec::new();
+    while !bytes.is_empty() {
+        instrs.push(T::decode_from(&mut bytes)?);
+    }
+    Ok(instrs)
+}
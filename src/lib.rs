@@ -2,6 +2,34 @@
 
 pub use union_fn_macro::union_fn;
 
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+pub use arbitrary;
+
+#[cfg(feature = "binary")]
+#[doc(hidden)]
+pub use bytemuck;
+
+#[cfg(feature = "binary")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "binary")]
+mod binary;
+#[doc(hidden)]
+pub mod private;
+
+#[cfg(feature = "binary")]
+pub use self::binary::{decode_all, encode_all, BinaryDecode, BinaryEncode};
+
+use core::ops::ControlFlow;
+
 /// Allows `#[union_fn]` types with context to be called as functions.
 ///
 /// # Note
@@ -10,6 +38,23 @@ pub use union_fn_macro::union_fn;
 pub trait Call: UnionFn {
     /// Calls the union function.
     fn call(self) -> <Self as UnionFn>::Output;
+
+    /// Sequences `self` with `other` so that both are called in order.
+    fn then(self, other: Self) -> Pair<Self>
+    where
+        Self: Sized,
+    {
+        Pair(self, other)
+    }
+
+    /// Transforms the output of `self` via `f` when called.
+    fn map_output<U, F>(self, f: F) -> MapOutput<Self, F>
+    where
+        Self: Sized,
+        F: Fn(<Self as UnionFn>::Output) -> U,
+    {
+        MapOutput(self, f)
+    }
 }
 
 /// Allows `#[union_fn]` types with context to be called as functions.
@@ -23,6 +68,204 @@ pub trait CallWithContext: UnionFn {
 
     /// Calls the union function with the given context.
     fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output;
+
+    /// Sequences `self` with `other` so that both are called in order with the same context.
+    fn then(self, other: Self) -> Pair<Self>
+    where
+        Self: Sized,
+    {
+        Pair(self, other)
+    }
+
+    /// Transforms the output of `self` via `f` when called.
+    fn map_output<U, F>(self, f: F) -> MapOutput<Self, F>
+    where
+        Self: Sized,
+        F: Fn(<Self as UnionFn>::Output) -> U,
+    {
+        MapOutput(self, f)
+    }
+
+    /// Wraps `self` so that `pre` and `post` are called before and after it with the same context.
+    fn instrument<Pre, Post>(self, pre: Pre, post: Post) -> Instrumented<Self, Pre, Post>
+    where
+        Self: Sized,
+        Pre: Fn(&Self, &mut Self::Context),
+        Post: Fn(&Self, &mut Self::Context, &<Self as UnionFn>::Output),
+    {
+        Instrumented {
+            inner: self,
+            pre,
+            post,
+        }
+    }
+
+    /// Pairs `self` with the owned `ctx` so that it can be called without a context.
+    fn with_context(self, ctx: Self::Context) -> ContextedOpt<Self>
+    where
+        Self: Sized,
+    {
+        ContextedOpt { inner: self, ctx }
+    }
+}
+
+/// Allows `#[union_fn]` types with `unsafe` methods and without context to be called as functions.
+///
+/// # Note
+///
+/// This trait automatically implemented by `#[union_fn]` expansions
+/// instead of [`Call`] if all `#[union_fn]` methods are `unsafe fn`.
+pub trait UnsafeCall: UnionFn {
+    /// Calls the union function.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the safety contract of the called `#[union_fn]` method.
+    unsafe fn call(self) -> <Self as UnionFn>::Output;
+}
+
+/// Allows `#[union_fn]` types with `unsafe` methods and with context to be called as functions.
+///
+/// # Note
+///
+/// This trait automatically implemented by `#[union_fn]` expansions
+/// instead of [`CallWithContext`] if all `#[union_fn]` methods are `unsafe fn`.
+pub trait UnsafeCallWithContext: UnionFn {
+    /// The shared execution context.
+    type Context;
+
+    /// Calls the union function with the given context.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the safety contract of the called `#[union_fn]` method.
+    unsafe fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output;
+}
+
+/// Allows `#[union_fn]` types with `async` methods and without context to be called as functions.
+///
+/// # Note
+///
+/// This trait automatically implemented by `#[union_fn(no_opt)]` expansions
+/// instead of [`Call`] if all `#[union_fn]` methods are `async fn`.
+#[cfg(feature = "async")]
+pub trait AsyncCall: UnionFn {
+    /// Calls the union function asynchronously.
+    fn call_async(self) -> impl core::future::Future<Output = <Self as UnionFn>::Output>;
+}
+
+/// Allows `#[union_fn]` types with `async` methods and with context to be called as functions.
+///
+/// # Note
+///
+/// This trait automatically implemented by `#[union_fn(no_opt)]` expansions
+/// instead of [`CallWithContext`] if all `#[union_fn]` methods are `async fn`.
+#[cfg(feature = "async")]
+pub trait AsyncCallWithContext: UnionFn {
+    /// The shared execution context.
+    type Context;
+
+    /// Calls the union function asynchronously with the given context.
+    fn call_async(
+        self,
+        ctx: &mut Self::Context,
+    ) -> impl core::future::Future<Output = <Self as UnionFn>::Output>;
+}
+
+/// Allows `#[union_fn]` types without context and with fallible output to be called as functions.
+///
+/// # Note
+///
+/// This trait is automatically implemented for all [`Call`] types
+/// that have a [`Result`] as their [`UnionFn::Output`] type.
+pub trait TryCall: Call {
+    /// The type returned in the success case.
+    type Ok;
+    /// The type returned in the error case.
+    type Error;
+
+    /// Calls the union function and returns its fallible result.
+    fn try_call(self) -> Result<Self::Ok, Self::Error>;
+
+    /// Calls the union function and returns `default` in the error case.
+    #[inline]
+    fn call_or(self, default: Self::Ok) -> Self::Ok
+    where
+        Self: Sized,
+    {
+        self.try_call().unwrap_or(default)
+    }
+
+    /// Calls the union function and returns `Default::default()` in the error case.
+    #[inline]
+    fn call_or_default(self) -> Self::Ok
+    where
+        Self: Sized,
+        Self::Ok: Default,
+    {
+        self.try_call().unwrap_or_default()
+    }
+}
+
+impl<T, Ok, Error> TryCall for T
+where
+    T: Call + UnionFn<Output = Result<Ok, Error>>,
+{
+    type Ok = Ok;
+    type Error = Error;
+
+    #[inline]
+    fn try_call(self) -> Result<Self::Ok, Self::Error> {
+        <T as Call>::call(self)
+    }
+}
+
+/// Allows `#[union_fn]` types with context and with fallible output to be called as functions.
+///
+/// # Note
+///
+/// This trait is automatically implemented for all [`CallWithContext`] types
+/// that have a [`Result`] as their [`UnionFn::Output`] type.
+pub trait TryCallWithContext: CallWithContext {
+    /// The type returned in the success case.
+    type Ok;
+    /// The type returned in the error case.
+    type Error;
+
+    /// Calls the union function with the given context and returns its fallible result.
+    fn try_call(self, ctx: &mut Self::Context) -> Result<Self::Ok, Self::Error>;
+
+    /// Calls the union function with the given context and returns `default` in the error case.
+    #[inline]
+    fn call_or(self, ctx: &mut Self::Context, default: Self::Ok) -> Self::Ok
+    where
+        Self: Sized,
+    {
+        self.try_call(ctx).unwrap_or(default)
+    }
+
+    /// Calls the union function with the given context and returns `Default::default()` in the error case.
+    #[inline]
+    fn call_or_default(self, ctx: &mut Self::Context) -> Self::Ok
+    where
+        Self: Sized,
+        Self::Ok: Default,
+    {
+        self.try_call(ctx).unwrap_or_default()
+    }
+}
+
+impl<T, Ok, Error> TryCallWithContext for T
+where
+    T: CallWithContext + UnionFn<Output = Result<Ok, Error>>,
+{
+    type Ok = Ok;
+    type Error = Error;
+
+    #[inline]
+    fn try_call(self, ctx: &mut Self::Context) -> Result<Self::Ok, Self::Error> {
+        <T as CallWithContext>::call(self, ctx)
+    }
 }
 
 /// Allows `#[union_fn]` types to convert to their optimized instance.
@@ -42,6 +285,64 @@ pub trait IntoOpt {
     fn into_opt(self) -> Self::Opt;
 }
 
+/// Allows call optimized `#[union_fn]` types to convert back to their `enum` representation.
+///
+/// # Note
+///
+/// This trait is automatically implemented by `#[union_fn]` expansions
+/// if the `checked_dispatch` crate feature is enabled.
+pub trait IntoEnum {
+    /// The `enum` representation of the `#[union_fn]` type.
+    type Enum;
+
+    /// Converts the call optimized `#[union_fn]` type back to its `enum` representation.
+    ///
+    /// Returns `None` if the stored discriminant does not refer to any `#[union_fn]` method.
+    fn into_enum(self) -> Option<Self::Enum>;
+}
+
+/// Allows to store the packed arguments of call optimized `#[union_fn]` types separately.
+///
+/// # Note
+///
+/// This trait is automatically implemented by `#[union_fn]` expansions
+/// for call optimized types that implement [`CallWithContext`].
+pub trait SplitArgs: CallWithContext + Sized {
+    /// The remainder of the call optimized type without its packed arguments, e.g. its handler.
+    type Head: Copy;
+
+    /// Splits `self` into its head and its packed arguments.
+    fn split_args(self) -> (Self::Head, <Self as UnionFn>::Args);
+
+    /// Joins the `head` and `args` back into the call optimized type.
+    ///
+    /// # Safety
+    ///
+    /// The `head` and `args` must have been split from the same value via [`SplitArgs::split_args`].
+    unsafe fn join_args(head: Self::Head, args: <Self as UnionFn>::Args) -> Self;
+
+    /// Calls the `head` with `ctx` and the `args` stored elsewhere.
+    ///
+    /// # Safety
+    ///
+    /// The `head` and `args` must have been split from the same value via [`SplitArgs::split_args`].
+    unsafe fn call_split(
+        head: Self::Head,
+        ctx: &mut Self::Context,
+        args: &<Self as UnionFn>::Args,
+    ) -> <Self as UnionFn>::Output;
+}
+
+/// Allows to query which `#[union_fn]` method an instance represents.
+///
+/// # Note
+///
+/// This trait is automatically implemented for the enum by `#[union_fn]` expansions.
+pub trait Discriminant {
+    /// Returns the zero-based index of the `#[union_fn]` method represented by `self`.
+    fn discriminant(&self) -> usize;
+}
+
 /// Stores information about a `#[union_fn]` macro expansion.
 ///
 /// This helps to link different generated types together and
@@ -55,4 +356,801 @@ pub trait UnionFn {
     type Output;
     /// Type responsible to hold call optimized parameters.
     type Args;
+
+    /// The size of the [`UnionFn::Args`] type in bytes.
+    ///
+    /// Useful to assert upper bounds on the size of the arguments at compile time.
+    const ARGS_SIZE: usize = core::mem::size_of::<Self::Args>();
+}
+
+/// Static information about a single method of a `#[union_fn]` trait.
+///
+/// # Note
+///
+/// Returned by the `variants` method of the generated enum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VariantInfo {
+    /// The name of the method.
+    pub name: &'static str,
+    /// The discriminant of the method as returned by the `discriminant` method of the generated enum.
+    ///
+    /// This is also the index of the method within the slice returned by `variants`.
+    pub discriminant: usize,
+    /// The size of the method parameters in bytes.
+    pub args_size: usize,
+}
+
+/// Errors that may occur when parsing a `#[union_fn]` enum from its textual representation.
+///
+/// # Note
+///
+/// The textual representation is `method_name(arg1, arg2)` as produced by its `Display` impl.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input is not of the form `method_name(args)`.
+    InvalidSyntax,
+    /// The method name does not refer to any `#[union_fn]` method.
+    UnknownMethod,
+    /// The number of arguments does not match the number of method parameters.
+    MismatchingArgs,
+    /// The argument at the given zero-based index failed to parse.
+    InvalidArg(usize),
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::InvalidSyntax => write!(f, "expected `method_name(args)`"),
+            Self::UnknownMethod => write!(f, "encountered unknown method name"),
+            Self::MismatchingArgs => write!(f, "encountered mismatching number of arguments"),
+            Self::InvalidArg(n) => write!(f, "failed to parse argument at index {n}"),
+        }
+    }
+}
+
+/// Sequences two `#[union_fn]` instructions into a single callable.
+///
+/// Calling a [`Pair`] calls both instructions in order and returns the output of the second.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Pair<I>(pub I, pub I);
+
+impl<I> UnionFn for Pair<I>
+where
+    I: UnionFn,
+{
+    type Output = <I as UnionFn>::Output;
+    type Args = <I as UnionFn>::Args;
+}
+
+impl<I> Call for Pair<I>
+where
+    I: Call,
+{
+    #[inline]
+    fn call(self) -> <Self as UnionFn>::Output {
+        self.0.call();
+        self.1.call()
+    }
+}
+
+impl<I> CallWithContext for Pair<I>
+where
+    I: CallWithContext,
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        self.0.call(ctx);
+        self.1.call(ctx)
+    }
+}
+
+/// Repeats a `#[union_fn]` instruction `N` times with `N` known at compile time.
+///
+/// Calling a [`Repeat`] returns the output of the last call or `None` if `N` is zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Repeat<I, const N: usize>(pub I);
+
+impl<I, const N: usize> UnionFn for Repeat<I, N>
+where
+    I: UnionFn,
+{
+    type Output = Option<<I as UnionFn>::Output>;
+    type Args = <I as UnionFn>::Args;
+}
+
+impl<I, const N: usize> Call for Repeat<I, N>
+where
+    I: Call + Copy,
+{
+    #[inline]
+    fn call(self) -> <Self as UnionFn>::Output {
+        (0..N).fold(None, |_, _| Some(self.0.call()))
+    }
+}
+
+impl<I, const N: usize> CallWithContext for Repeat<I, N>
+where
+    I: CallWithContext + Copy,
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        (0..N).fold(None, |_, _| Some(self.0.call(ctx)))
+    }
+}
+
+/// Repeats a `#[union_fn]` instruction `count` times with `count` known at runtime.
+///
+/// Calling a [`RepeatDynamic`] returns the output of the last call or `None` if `count` is zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RepeatDynamic<I> {
+    /// The repeated instruction.
+    pub instr: I,
+    /// The number of repetitions.
+    pub count: usize,
+}
+
+impl<I> UnionFn for RepeatDynamic<I>
+where
+    I: UnionFn,
+{
+    type Output = Option<<I as UnionFn>::Output>;
+    type Args = <I as UnionFn>::Args;
+}
+
+impl<I> Call for RepeatDynamic<I>
+where
+    I: Call + Copy,
+{
+    #[inline]
+    fn call(self) -> <Self as UnionFn>::Output {
+        (0..self.count).fold(None, |_, _| Some(self.instr.call()))
+    }
+}
+
+impl<I> CallWithContext for RepeatDynamic<I>
+where
+    I: CallWithContext + Copy,
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        (0..self.count).fold(None, |_, _| Some(self.instr.call(ctx)))
+    }
+}
+
+/// Guards a `#[union_fn]` instruction by a predicate on its context.
+///
+/// Calling a [`Conditional`] only calls the instruction if the predicate returns `true`
+/// and otherwise returns `Default::default()` without calling it.
+#[derive(Copy, Clone)]
+pub struct Conditional<I, F>(pub I, pub F);
+
+impl<I, F> UnionFn for Conditional<I, F>
+where
+    I: UnionFn,
+{
+    type Output = <I as UnionFn>::Output;
+    type Args = <I as UnionFn>::Args;
+}
+
+impl<I, F> CallWithContext for Conditional<I, F>
+where
+    I: CallWithContext,
+    <I as UnionFn>::Output: Default,
+    F: Fn(&<I as CallWithContext>::Context) -> bool,
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        if !(self.1)(ctx) {
+            return Default::default();
+        }
+        self.0.call(ctx)
+    }
+}
+
+/// Transforms the output of a `#[union_fn]` instruction.
+///
+/// Calling a [`MapOutput`] calls the instruction and returns its output transformed by the function.
+#[derive(Copy, Clone)]
+pub struct MapOutput<I, F>(pub I, pub F);
+
+impl<I, F, U> UnionFn for MapOutput<I, F>
+where
+    I: UnionFn,
+    F: Fn(<I as UnionFn>::Output) -> U,
+{
+    type Output = U;
+    type Args = <I as UnionFn>::Args;
+}
+
+impl<I, F, U> Call for MapOutput<I, F>
+where
+    I: Call,
+    F: Fn(<I as UnionFn>::Output) -> U,
+{
+    #[inline]
+    fn call(self) -> <Self as UnionFn>::Output {
+        (self.1)(self.0.call())
+    }
+}
+
+impl<I, F, U> CallWithContext for MapOutput<I, F>
+where
+    I: CallWithContext,
+    F: Fn(<I as UnionFn>::Output) -> U,
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        (self.1)(self.0.call(ctx))
+    }
+}
+
+/// Wraps a `#[union_fn]` instruction with hooks that are called before and after it.
+///
+/// Calling an [`Instrumented`] calls `pre`, then the instruction and then `post` with its output.
+/// This allows to add logging, metrics or coverage tracking to any instruction.
+#[derive(Copy, Clone)]
+pub struct Instrumented<I, Pre, Post> {
+    /// The wrapped instruction.
+    pub inner: I,
+    /// The hook called before the instruction.
+    pub pre: Pre,
+    /// The hook called after the instruction with its output.
+    pub post: Post,
+}
+
+impl<I, Pre, Post> UnionFn for Instrumented<I, Pre, Post>
+where
+    I: UnionFn,
+{
+    type Output = <I as UnionFn>::Output;
+    type Args = <I as UnionFn>::Args;
+}
+
+impl<I, Pre, Post> CallWithContext for Instrumented<I, Pre, Post>
+where
+    I: CallWithContext + Copy,
+    Pre: Fn(&I, &mut <I as CallWithContext>::Context),
+    Post: Fn(&I, &mut <I as CallWithContext>::Context, &<I as UnionFn>::Output),
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        (self.pre)(&self.inner, ctx);
+        let output = self.inner.call(ctx);
+        (self.post)(&self.inner, ctx, &output);
+        output
+    }
+}
+
+/// A `#[union_fn]` instruction paired with its own context.
+///
+/// Calling a [`ContextedOpt`] calls the instruction with the owned context.
+/// This allows to store instructions as callbacks, e.g. via [`ContextedOpt::into_fn`].
+pub struct ContextedOpt<I>
+where
+    I: CallWithContext,
+{
+    /// The wrapped instruction.
+    pub inner: I,
+    /// The owned context of the instruction.
+    pub ctx: <I as CallWithContext>::Context,
+}
+
+impl<I> Clone for ContextedOpt<I>
+where
+    I: CallWithContext + Clone,
+    <I as CallWithContext>::Context: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            ctx: self.ctx.clone(),
+        }
+    }
+}
+
+impl<I> ContextedOpt<I>
+where
+    I: CallWithContext,
+{
+    /// Calls the instruction with the owned context without consuming `self`.
+    #[inline]
+    pub fn call_mut(&mut self) -> <I as UnionFn>::Output
+    where
+        I: Copy,
+    {
+        self.inner.call(&mut self.ctx)
+    }
+
+    /// Converts `self` into a closure calling the instruction with the owned context.
+    pub fn into_fn(mut self) -> impl FnMut() -> <I as UnionFn>::Output
+    where
+        I: Copy,
+    {
+        move || self.call_mut()
+    }
+
+    /// Returns the owned context.
+    pub fn into_context(self) -> <I as CallWithContext>::Context {
+        self.ctx
+    }
+}
+
+impl<I> UnionFn for ContextedOpt<I>
+where
+    I: CallWithContext,
+{
+    type Output = <I as UnionFn>::Output;
+    type Args = <I as UnionFn>::Args;
+}
+
+impl<I> Call for ContextedOpt<I>
+where
+    I: CallWithContext,
+{
+    #[inline]
+    fn call(mut self) -> <Self as UnionFn>::Output {
+        self.inner.call(&mut self.ctx)
+    }
+}
+
+/// The output of `#[union_fn]` instructions executed in sequence via [`execute_slice`].
+///
+/// # Note
+///
+/// Implemented for `()` which never stops execution as well as for [`ControlFlow`]
+/// and [`Result`] which stop execution on [`ControlFlow::Break`] and [`Err`] respectively.
+pub trait Flow {
+    /// Returns the output of an empty sequence of instructions.
+    fn proceed() -> Self;
+
+    /// Returns `true` if the execution of the sequence of instructions stops after `self`.
+    fn is_break(&self) -> bool;
+}
+
+impl Flow for () {
+    #[inline]
+    fn proceed() -> Self {}
+
+    #[inline]
+    fn is_break(&self) -> bool {
+        false
+    }
+}
+
+impl<B, C> Flow for ControlFlow<B, C>
+where
+    C: Flow,
+{
+    #[inline]
+    fn proceed() -> Self {
+        ControlFlow::Continue(C::proceed())
+    }
+
+    #[inline]
+    fn is_break(&self) -> bool {
+        match self {
+            ControlFlow::Continue(output) => output.is_break(),
+            ControlFlow::Break(_) => true,
+        }
+    }
+}
+
+impl<T, E> Flow for Result<T, E>
+where
+    T: Flow,
+{
+    #[inline]
+    fn proceed() -> Self {
+        Ok(T::proceed())
+    }
+
+    #[inline]
+    fn is_break(&self) -> bool {
+        match self {
+            Ok(output) => output.is_break(),
+            Err(_) => true,
+        }
+    }
+}
+
+/// Executes the `instrs` in order with the given `ctx`.
+///
+/// Returns the output of the first instruction that stops execution according to [`Flow`]
+/// or otherwise the output of the last instruction or [`Flow::proceed`] if `instrs` is empty.
+///
+/// # Note
+///
+/// For `Result<ControlFlow<R>, E>` outputs execution stops on `Ok(ControlFlow::Break(_))` or `Err(_)`.
+pub fn execute_slice<I, C, O>(instrs: &[I], ctx: &mut C) -> O
+where
+    I: CallWithContext<Context = C> + UnionFn<Output = O> + Copy,
+    O: Flow,
+{
+    let mut output = O::proceed();
+    for instr in instrs {
+        output = instr.call(ctx);
+        if output.is_break() {
+            break;
+        }
+    }
+    output
+}
+
+/// Executes all `instrs` in order with the given `ctx` and folds their outputs via `f`.
+///
+/// Starts with `init` and returns the accumulated value after the last instruction.
+/// Unlike [`execute_slice`] this never stops early, e.g. to collect all errors of a program.
+pub fn fold_instructions<I, B, F>(
+    instrs: &[I],
+    ctx: &mut <I as CallWithContext>::Context,
+    init: B,
+    mut f: F,
+) -> B
+where
+    I: CallWithContext + Copy,
+    F: FnMut(B, <I as UnionFn>::Output) -> B,
+{
+    instrs
+        .iter()
+        .fold(init, |acc, instr| f(acc, instr.call(ctx)))
+}
+
+/// Returns an iterator over the `instrs` that represent the method with the given `discriminant`.
+///
+/// # Note
+///
+/// Together with the generated `variants` method this allows to build
+/// frequency tables of the methods used in a sequence of instructions.
+pub fn by_variant<I>(instrs: &[I], discriminant: usize) -> impl Iterator<Item = &I>
+where
+    I: Discriminant,
+{
+    instrs
+        .iter()
+        .filter(move |instr| instr.discriminant() == discriminant)
+}
+
+/// A sequence of `#[union_fn]` instructions that is executed in order.
+///
+/// # Note
+///
+/// Executes its instructions via [`execute_slice`].
+#[derive(Debug)]
+pub struct InstructionSlice<'a, I>(&'a [I]);
+
+impl<I> Copy for InstructionSlice<'_, I> {}
+
+impl<I> Clone for InstructionSlice<'_, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I> From<&'a [I]> for InstructionSlice<'a, I> {
+    fn from(instrs: &'a [I]) -> Self {
+        Self::new(instrs)
+    }
+}
+
+impl<'a, I> InstructionSlice<'a, I> {
+    /// Creates a new [`InstructionSlice`] for the given `instrs`.
+    pub const fn new(instrs: &'a [I]) -> Self {
+        Self(instrs)
+    }
+
+    /// Returns the underlying slice of instructions.
+    pub const fn as_slice(&self) -> &'a [I] {
+        self.0
+    }
+
+    /// Returns the number of instructions.
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no instructions.
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the instructions.
+    pub fn iter(&self) -> core::slice::Iter<'a, I> {
+        self.0.iter()
+    }
+
+    /// Returns the instruction at index `i` if any.
+    pub fn get(&self, i: usize) -> Option<&'a I> {
+        self.0.get(i)
+    }
+
+    /// Executes the instructions in order with the given `ctx`.
+    ///
+    /// See [`execute_slice`] for the returned output.
+    pub fn execute(&self, ctx: &mut <I as CallWithContext>::Context) -> <I as UnionFn>::Output
+    where
+        I: CallWithContext + Copy,
+        <I as UnionFn>::Output: Flow,
+    {
+        execute_slice(self.0, ctx)
+    }
+}
+
+impl<'a, I> IntoIterator for InstructionSlice<'a, I> {
+    type Item = &'a I;
+    type IntoIter = core::slice::Iter<'a, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Fluently builds a sequence of `#[union_fn]` instructions.
+///
+/// # Note
+///
+/// Requires the `alloc` crate feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct InstructionBuilder<I>(alloc::vec::Vec<I>);
+
+#[cfg(feature = "alloc")]
+impl<I> Default for InstructionBuilder<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> InstructionBuilder<I> {
+    /// Creates a new empty [`InstructionBuilder`].
+    pub const fn new() -> Self {
+        Self(alloc::vec::Vec::new())
+    }
+
+    /// Pushes `instr` to the end of the built sequence.
+    #[must_use]
+    pub fn push(mut self, instr: I) -> Self {
+        self.0.push(instr);
+        self
+    }
+
+    /// Pushes all `instrs` in order to the end of the built sequence.
+    #[must_use]
+    pub fn extend(mut self, instrs: impl IntoIterator<Item = I>) -> Self {
+        self.0.extend(instrs);
+        self
+    }
+
+    /// Returns the number of instructions pushed so far.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no instructions have been pushed so far.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the built sequence of instructions.
+    pub fn build(self) -> alloc::vec::Vec<I> {
+        self.0
+    }
+}
+
+/// A call optimized `#[union_fn]` type with heap allocated arguments.
+///
+/// # Note
+///
+/// Keeps instruction slots small for `#[union_fn]` types with large arguments
+/// at the cost of one heap allocation per constructed instruction.
+/// Only the packed arguments are moved to the heap while the handler stays inline.
+///
+/// Requires the `alloc` crate feature.
+#[cfg(feature = "alloc")]
+pub struct OptBoxed<I>
+where
+    I: SplitArgs,
+{
+    head: <I as SplitArgs>::Head,
+    args: alloc::boxed::Box<<I as UnionFn>::Args>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> OptBoxed<I>
+where
+    I: SplitArgs,
+{
+    /// Moves the packed arguments of `instr` to the heap.
+    pub fn new(instr: I) -> Self {
+        let (head, args) = instr.split_args();
+        Self {
+            head,
+            args: alloc::boxed::Box::new(args),
+        }
+    }
+
+    /// Returns the instruction with its packed arguments moved back inline.
+    pub fn into_inner(self) -> I {
+        // SAFETY: `head` and `args` have been split from the same value in `new`.
+        unsafe { I::join_args(self.head, *self.args) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Clone for OptBoxed<I>
+where
+    I: SplitArgs,
+    <I as UnionFn>::Args: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head,
+            args: self.args.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> UnionFn for OptBoxed<I>
+where
+    I: SplitArgs,
+{
+    type Output = <I as UnionFn>::Output;
+    type Args = <I as UnionFn>::Args;
+}
+
+#[cfg(feature = "alloc")]
+impl<I> CallWithContext for OptBoxed<I>
+where
+    I: SplitArgs,
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        // SAFETY: `head` and `args` have been split from the same value in `new`.
+        unsafe { I::call_split(self.head, ctx, &self.args) }
+    }
+}
+
+/// Object safe variant of [`CallWithContext`] for heap allocated extension instructions.
+///
+/// # Note
+///
+/// Automatically implemented for all `Copy` types implementing [`CallWithContext`].
+pub trait DynCallWithContext<C, O> {
+    /// Calls the union function with the given `ctx`.
+    fn call_dyn(&self, ctx: &mut C) -> O;
+}
+
+impl<T> DynCallWithContext<<T as CallWithContext>::Context, <T as UnionFn>::Output> for T
+where
+    T: CallWithContext + Copy,
+{
+    #[inline]
+    fn call_dyn(&self, ctx: &mut <T as CallWithContext>::Context) -> <T as UnionFn>::Output {
+        (*self).call(ctx)
+    }
+}
+
+/// Either a statically dispatched `#[union_fn]` instruction or a dynamic extension instruction.
+///
+/// # Note
+///
+/// Allows to extend an instruction set, e.g. via plugins, without recompiling it.
+/// Most instructions stay [`DynUnionFn::Static`] and only extension instructions
+/// pay for the heap allocation and dynamic dispatch of [`DynUnionFn::Dynamic`].
+///
+/// Requires the `alloc` crate feature.
+#[cfg(feature = "alloc")]
+pub enum DynUnionFn<I>
+where
+    I: CallWithContext,
+{
+    /// A statically dispatched instruction.
+    Static(I),
+    /// A dynamically dispatched extension instruction.
+    #[allow(clippy::type_complexity)]
+    Dynamic(
+        alloc::boxed::Box<
+            dyn DynCallWithContext<<I as CallWithContext>::Context, <I as UnionFn>::Output>,
+        >,
+    ),
+}
+
+#[cfg(feature = "alloc")]
+impl<I> DynUnionFn<I>
+where
+    I: CallWithContext,
+{
+    /// Creates a dynamically dispatched extension instruction from `instr`.
+    pub fn dynamic<T>(instr: T) -> Self
+    where
+        T: DynCallWithContext<<I as CallWithContext>::Context, <I as UnionFn>::Output> + 'static,
+    {
+        Self::Dynamic(alloc::boxed::Box::new(instr))
+    }
+
+    /// Calls the instruction with the given `ctx` without consuming `self`.
+    #[inline]
+    pub fn call_ref(&self, ctx: &mut <I as CallWithContext>::Context) -> <I as UnionFn>::Output
+    where
+        I: Copy,
+    {
+        match self {
+            Self::Static(instr) => instr.call(ctx),
+            Self::Dynamic(instr) => instr.call_dyn(ctx),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> From<I> for DynUnionFn<I>
+where
+    I: CallWithContext,
+{
+    fn from(instr: I) -> Self {
+        Self::Static(instr)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> UnionFn for DynUnionFn<I>
+where
+    I: CallWithContext,
+{
+    type Output = <I as UnionFn>::Output;
+    type Args = <I as UnionFn>::Args;
+}
+
+#[cfg(feature = "alloc")]
+impl<I> CallWithContext for DynUnionFn<I>
+where
+    I: CallWithContext,
+{
+    type Context = <I as CallWithContext>::Context;
+
+    #[inline]
+    fn call(self, ctx: &mut Self::Context) -> <Self as UnionFn>::Output {
+        match self {
+            Self::Static(instr) => instr.call(ctx),
+            Self::Dynamic(instr) => instr.call_dyn(ctx),
+        }
+    }
+}
+
+/// A saved copy of a `#[union_fn]` context that can be restored later.
+///
+/// # Note
+///
+/// Allows transactional execution of instructions by saving the context
+/// before a risky instruction and restoring it if the instruction failed.
+#[derive(Debug, Clone)]
+pub struct ContextSnapshot<C: Clone> {
+    /// The saved copy of the context.
+    saved: C,
+}
+
+impl<C: Clone> ContextSnapshot<C> {
+    /// Saves a copy of the given `ctx`.
+    pub fn save(ctx: &mut C) -> Self {
+        Self { saved: ctx.clone() }
+    }
+
+    /// Restores `ctx` to the saved copy.
+    pub fn restore(self, ctx: &mut C) {
+        *ctx = self.saved;
+    }
 }
@@ -0,0 +1,238 @@
+//! Implementation details of `#[union_fn]` expansions.
+//!
+//! # Note
+//!
+//! Nothing in here is part of the public API and may change at any time.
+
+use crate::ParseError;
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+#[cfg(feature = "binary")]
+pub use std::io;
+
+/// Formats via the wrapped closure.
+pub struct DebugFn<F>(pub F);
+
+impl<F> fmt::Debug for DebugFn<F>
+where
+    F: Fn(&mut fmt::Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Wraps a value of a `#[union_fn]` method parameter for formatting.
+///
+/// # Note
+///
+/// Uses the `Debug` or `Display` impl of the value if any via [`DebugViaImpl`]
+/// or [`DisplayViaImpl`] and otherwise falls back to [`DebugFallback`] or
+/// [`DisplayFallback`] using autoref based method resolution:
+///
+/// ```ignore
+/// (&FmtWrap(&value)).debug_fmt(f)
+/// ```
+pub struct FmtWrap<'a, T>(pub &'a T);
+
+/// Debug formats [`FmtWrap`] via the `Debug` impl of the wrapped value.
+pub trait DebugViaImpl {
+    /// Formats the wrapped value.
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+impl<T> DebugViaImpl for FmtWrap<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.0, f)
+    }
+}
+
+/// Debug formats [`FmtWrap`] as `..` if the wrapped value does not implement `Debug`.
+pub trait DebugFallback {
+    /// Formats the wrapped value.
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+impl<T> DebugFallback for &'_ FmtWrap<'_, T> {
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("..")
+    }
+}
+
+/// Display formats [`FmtWrap`] via the `Display` impl of the wrapped value.
+pub trait DisplayViaImpl {
+    /// Formats the wrapped value.
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+impl<T> DisplayViaImpl for FmtWrap<'_, T>
+where
+    T: fmt::Display,
+{
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)
+    }
+}
+
+/// Display formats [`FmtWrap`] as `..` if the wrapped value does not implement `Display`.
+pub trait DisplayFallback {
+    /// Formats the wrapped value.
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+impl<T> DisplayFallback for &'_ FmtWrap<'_, T> {
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("..")
+    }
+}
+
+/// Splits `input` of the form `method_name(args)` into its method name and arguments.
+///
+/// # Errors
+///
+/// If `input` is not of the form `method_name(args)`.
+pub fn parse_call(input: &str) -> Result<(&str, ParseArgs<'_>), ParseError> {
+    let (name, args) = input
+        .trim()
+        .strip_suffix(')')
+        .and_then(|input| input.split_once('('))
+        .ok_or(ParseError::InvalidSyntax)?;
+    Ok((name.trim_end(), ParseArgs::new(args)))
+}
+
+/// The comma separated arguments of a parsed `method_name(args)` input.
+///
+/// # Note
+///
+/// Commas nested within brackets or double quotes do not separate arguments,
+/// e.g. `(1, 2), "a, b"` are two arguments. Arguments whose textual representation
+/// contains other commas, e.g. a `String` displayed without quotes, cannot be parsed.
+pub struct ParseArgs<'a> {
+    /// The remaining arguments if any.
+    args: Option<&'a str>,
+    /// The zero-based index of the next argument.
+    index: usize,
+}
+
+impl<'a> ParseArgs<'a> {
+    /// Creates new [`ParseArgs`] for the given comma separated `args`.
+    fn new(args: &'a str) -> Self {
+        let args = args.trim();
+        Self {
+            args: (!args.is_empty()).then_some(args),
+            index: 0,
+        }
+    }
+
+    /// Returns the next argument and its zero-based index.
+    ///
+    /// # Errors
+    ///
+    /// If there are no more arguments.
+    pub fn next_arg(&mut self) -> Result<(usize, &'a str), ParseError> {
+        let args = self.args.ok_or(ParseError::MismatchingArgs)?;
+        let (arg, rest) = match Self::find_separator(args) {
+            Some(pos) => (&args[..pos], Some(&args[pos + 1..])),
+            None => (args, None),
+        };
+        self.args = rest;
+        let index = self.index;
+        self.index += 1;
+        Ok((index, arg.trim()))
+    }
+
+    /// Returns the position of the first comma in `args` that separates two arguments.
+    ///
+    /// Commas nested within brackets or double quotes are skipped.
+    fn find_separator(args: &str) -> Option<usize> {
+        let mut depth = 0_usize;
+        let mut in_quotes = false;
+        let mut escaped = false;
+        for (pos, ch) in args.char_indices() {
+            if in_quotes {
+                match ch {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_quotes = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match ch {
+                '"' => in_quotes = true,
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => return Some(pos),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Asserts that all arguments have been consumed.
+    ///
+    /// # Errors
+    ///
+    /// If there are remaining arguments.
+    pub fn finish(self) -> Result<(), ParseError> {
+        match self.args {
+            Some(_) => Err(ParseError::MismatchingArgs),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses a value of a `#[union_fn]` method parameter of type `T`.
+///
+/// # Note
+///
+/// Uses the `FromStr` impl of `T` if any via [`ParseViaImpl`] and otherwise
+/// falls back to [`ParseFallback`] using autoref based method resolution:
+///
+/// ```ignore
+/// (&ParseWrap::<T>::new()).parse_arg(arg)
+/// ```
+pub struct ParseWrap<T>(PhantomData<fn() -> T>);
+
+impl<T> ParseWrap<T> {
+    /// Creates a new [`ParseWrap`] for `T`.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for ParseWrap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses via the `FromStr` impl of `T`.
+pub trait ParseViaImpl<T> {
+    /// Parses the argument.
+    fn parse_arg(&self, arg: &str) -> Option<T>;
+}
+
+impl<T> ParseViaImpl<T> for ParseWrap<T>
+where
+    T: FromStr,
+{
+    fn parse_arg(&self, arg: &str) -> Option<T> {
+        arg.parse().ok()
+    }
+}
+
+/// Fails to parse if `T` does not implement `FromStr`.
+pub trait ParseFallback<T> {
+    /// Parses the argument.
+    fn parse_arg(&self, arg: &str) -> Option<T>;
+}
+
+impl<T> ParseFallback<T> for &'_ ParseWrap<T> {
+    fn parse_arg(&self, _arg: &str) -> Option<T> {
+        None
+    }
+}
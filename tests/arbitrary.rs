@@ -0,0 +1,45 @@
+#![cfg(feature = "arbitrary")]
+
+use union_fn::{
+    arbitrary::{Arbitrary as _, Unstructured},
+    union_fn, CallWithContext as _, IntoOpt as _,
+};
+
+#[union_fn(arbitrary)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i32) {
+        *value += i64::from(by);
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i32, rhs: i32) {
+        *value = i64::from(if *value == 0 { lhs } else { rhs });
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+#[test]
+fn arbitrary_covers_all_variants() {
+    let bytes: Vec<u8> = (0..=u8::MAX).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&bytes);
+    let mut seen = [false; 3];
+    while !u.is_empty() {
+        let Ok(instr) = Counter::arbitrary(&mut u) else {
+            break;
+        };
+        seen[instr.discriminant()] = true;
+        let mut lhs = 1;
+        let mut rhs = 1;
+        instr.call(&mut lhs);
+        instr.into_opt().call(&mut rhs);
+        assert_eq!(lhs, rhs);
+    }
+    assert_eq!(seen, [true; 3]);
+}
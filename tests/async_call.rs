@@ -0,0 +1,96 @@
+#![cfg(feature = "async")]
+
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+use union_fn::{union_fn, AsyncCall as _, AsyncCallWithContext as _};
+
+/// Polls `future` to completion and returns its output and the number of polls.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(core::ptr::null(), &VTABLE),
+        |_| (),
+        |_| (),
+        |_| (),
+    );
+    // SAFETY: the waker functions do nothing and never access the data pointer.
+    let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return (output, polls);
+        }
+    }
+}
+
+/// A future that is pending once before it resolves.
+async fn yield_now() {
+    let mut yielded = false;
+    core::future::poll_fn(|_| {
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        Poll::Pending
+    })
+    .await
+}
+
+/// A mock device that takes some time to respond.
+#[derive(Default)]
+pub struct Device {
+    reads: usize,
+    value: i64,
+}
+
+impl Device {
+    async fn read(&mut self) -> i64 {
+        yield_now().await;
+        self.reads += 1;
+        self.value
+    }
+}
+
+#[union_fn(no_opt)]
+trait Io {
+    type Context = Device;
+    type Output = i64;
+
+    /// Writes the `value` to the device.
+    async fn write(device: &mut Self::Context, value: i64) -> Self::Output {
+        device.value = value;
+        value
+    }
+
+    /// Reads the value of the device.
+    async fn read(device: &mut Self::Context) -> Self::Output {
+        device.read().await
+    }
+}
+
+#[union_fn(no_opt)]
+trait Pure {
+    /// Returns the sum of `lhs` and `rhs` after yielding once.
+    async fn add(lhs: i64, rhs: i64) -> i64 {
+        yield_now().await;
+        lhs + rhs
+    }
+}
+
+#[test]
+fn async_call_with_context() {
+    let mut device = Device::default();
+    assert_eq!(block_on(Io::write(42).call_async(&mut device)), (42, 1));
+    assert_eq!(block_on(Io::read().call_async(&mut device)), (42, 2));
+    assert_eq!(device.reads, 1);
+}
+
+#[test]
+fn async_call() {
+    assert_eq!(block_on(Pure::add(1, 2).call_async()), (3, 2));
+}
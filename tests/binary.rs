@@ -0,0 +1,88 @@
+#![cfg(feature = "binary")]
+
+use union_fn::{decode_all, encode_all, union_fn, BinaryDecode as _, BinaryEncode as _};
+
+#[union_fn(binary)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i32, rhs: u8) {
+        *value = if *value == 0 { lhs.into() } else { rhs.into() };
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+#[test]
+fn encode_layout() {
+    let mut buf = Vec::new();
+    Counter::select(-1, 7).encode_to(&mut buf).unwrap();
+    let mut expected = vec![1];
+    expected.extend_from_slice(&(-1_i32).to_ne_bytes());
+    expected.push(7);
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn roundtrip_all() {
+    let instrs = [
+        Counter::bump_by(42),
+        Counter::reset(),
+        Counter::select(-5, 255),
+        Counter::bump_by(i64::MIN),
+    ];
+    let mut buf = Vec::new();
+    encode_all(&instrs, &mut buf).unwrap();
+    assert_eq!(buf.len(), 9 + 1 + 6 + 9);
+    let decoded: Vec<Counter> = decode_all(&mut &buf[..]).unwrap();
+    assert_eq!(decoded.len(), instrs.len());
+    for (decoded, instr) in decoded.iter().zip(&instrs) {
+        assert_eq!(decoded.discriminant(), instr.discriminant());
+        assert_eq!(decoded.as_bump_by(), instr.as_bump_by());
+        assert_eq!(decoded.as_select(), instr.as_select());
+    }
+}
+
+#[test]
+fn decode_errors() {
+    assert!(Counter::decode_from(&mut &[3_u8][..]).is_err());
+    assert!(Counter::decode_from(&mut &[0_u8, 1, 2][..]).is_err());
+    assert!(decode_all::<Counter>(&mut &[2_u8, 0][..]).is_err());
+}
+
+#[test]
+fn encode_decode_with_cursor() {
+    let instrs = [
+        Counter::select(-5, 255),
+        Counter::reset(),
+        Counter::bump_by(7),
+    ];
+    let mut buf = Vec::new();
+    for instr in &instrs {
+        instr.encode(&mut buf);
+    }
+    let mut cursor = 0;
+    let mut decoded = Vec::new();
+    while let Some(instr) = Counter::decode(&buf, &mut cursor) {
+        decoded.push(instr);
+    }
+    assert_eq!(cursor, buf.len());
+    assert_eq!(decoded.len(), instrs.len());
+    for (decoded, instr) in decoded.iter().zip(&instrs) {
+        assert_eq!(decoded.discriminant(), instr.discriminant());
+        assert_eq!(decoded.as_select(), instr.as_select());
+        assert_eq!(decoded.as_bump_by(), instr.as_bump_by());
+    }
+    let mut cursor = 1;
+    assert!(Counter::decode(&buf, &mut cursor).is_none());
+    assert_eq!(cursor, 1);
+}
@@ -4,10 +4,11 @@ pub use union_fn::CallWithContext as _;
 const _: () = {
     const fn is_send<T: Send>() {}
     const fn is_sync<T: Sync>() {}
-    let _ = is_send::<Instruction>();
-    let _ = is_sync::<Instruction>();
-    let _ = is_send::<<Instruction as ::union_fn::IntoOpt>::Opt>();
-    let _ = is_sync::<<Instruction as ::union_fn::IntoOpt>::Opt>();
+    is_send::<Instruction>();
+    is_sync::<Instruction>();
+    is_send::<<Instruction as ::union_fn::IntoOpt>::Opt>();
+    is_sync::<<Instruction as ::union_fn::IntoOpt>::Opt>();
+    assert!(<Instruction as ::union_fn::UnionFn>::ARGS_SIZE <= 8);
 };
 
 #[union_fn]
@@ -79,7 +80,7 @@ impl ExecutionContext {
         self.instrs
             .get(self.ip)
             .copied()
-            .ok_or_else(|| TrapCode::UnreachableCodeReached)?
+            .ok_or(TrapCode::UnreachableCodeReached)?
             .call(self)
     }
 
@@ -94,7 +95,7 @@ impl ExecutionContext {
     }
 
     pub fn pop(&mut self) -> Result<u64, TrapCode> {
-        self.stack.pop().ok_or_else(|| TrapCode::StackUnderflow)
+        self.stack.pop().ok_or(TrapCode::StackUnderflow)
     }
 
     pub fn push(&mut self, value: u64) {
@@ -107,7 +108,7 @@ impl ExecutionContext {
             .rev()
             .nth(depth)
             .copied()
-            .ok_or_else(|| TrapCode::StackUnderflow)
+            .ok_or(TrapCode::StackUnderflow)
     }
 }
 
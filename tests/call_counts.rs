@@ -0,0 +1,41 @@
+#![cfg(feature = "call_counts")]
+
+use union_fn::{union_fn, CallWithContext as _, IntoOpt as _};
+
+#[union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Disabled method that is not counted.
+    #[cfg(any())]
+    fn double(value: &mut Self::Context) {
+        *value *= 2;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+#[test]
+fn call_counts() {
+    assert_eq!(Counter::call_counts(), [("bump_by", 0), ("reset", 0)]);
+    let program = [
+        Counter::bump_by(1).into_opt(),
+        Counter::bump_by(2).into_opt(),
+        Counter::reset().into_opt(),
+    ];
+    let mut value = 0;
+    for _ in 0..10 {
+        for instr in program {
+            instr.call(&mut value);
+        }
+    }
+    assert_eq!(Counter::call_counts(), [("bump_by", 20), ("reset", 10)]);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "checked_dispatch")]
+
+use union_fn::{union_fn, CallWithContext as _, IntoEnum as _, IntoOpt as _};
+
+#[union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = if *value == 0 { lhs } else { rhs };
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+#[test]
+fn into_enum_roundtrip() {
+    let e: Counter = Counter::bump_by(5).into_opt().into_enum().unwrap();
+    assert_eq!(e.as_bump_by(), Some(5));
+    let e = Counter::select(1, 2).into_opt().into_enum().unwrap();
+    assert_eq!(e.as_select(), Some((1, 2)));
+    let e = Counter::reset().into_opt().into_enum().unwrap();
+    assert!(e.is_reset());
+}
+
+#[test]
+fn opt_discriminant() {
+    assert_eq!(Counter::bump_by(5).into_opt().discriminant(), 0);
+    assert_eq!(Counter::select(1, 2).into_opt().discriminant(), 1);
+    assert_eq!(Counter::reset().into_opt().discriminant(), 2);
+    let mut value = 0;
+    Counter::select(7, 8).into_opt().call(&mut value);
+    assert_eq!(value, 7);
+}
+
+#[test]
+fn opt_debug() {
+    let opt = Counter::select(1, 2).into_opt();
+    let debug = format!("{opt:?}");
+    assert!(debug.starts_with("CounterOpt { handler: 0x"), "{debug}");
+    assert!(
+        debug.ends_with("discriminant: 1, args: select(1, 2) }"),
+        "{debug}"
+    );
+}
@@ -0,0 +1,148 @@
+use core::cell::Cell;
+use union_fn::{
+    union_fn, Call as _, CallWithContext as _, Conditional, Instrumented, IntoOpt as _, MapOutput,
+    Pair, Repeat, RepeatDynamic,
+};
+
+#[union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) -> i64 {
+        *value += by;
+        *value
+    }
+
+    /// Doubles the value.
+    fn double(value: &mut Self::Context) -> i64 {
+        *value *= 2;
+        *value
+    }
+}
+
+#[union_fn]
+trait Literal {
+    /// Returns the `value`.
+    fn constant(value: i64) -> i64 {
+        value
+    }
+}
+
+#[test]
+fn pair_calls_in_order() {
+    let mut value = 1;
+    let pair = Counter::bump_by(2).then(Counter::double());
+    assert_eq!(pair.call(&mut value), 6);
+    assert_eq!(value, 6);
+    let pair = Pair(Counter::double().into_opt(), Counter::bump_by(2).into_opt());
+    assert_eq!(pair.call(&mut value), 14);
+    assert_eq!(value, 14);
+}
+
+#[test]
+fn pair_without_context() {
+    assert_eq!(Literal::constant(1).then(Literal::constant(2)).call(), 2);
+}
+
+#[test]
+fn repeat_static_count() {
+    let mut value = 1;
+    assert_eq!(Repeat::<_, 3>(Counter::double()).call(&mut value), Some(8));
+    assert_eq!(value, 8);
+    assert_eq!(
+        Repeat::<_, 0>(Counter::double().into_opt()).call(&mut value),
+        None
+    );
+    assert_eq!(value, 8);
+    assert_eq!(Repeat::<_, 2>(Literal::constant(5)).call(), Some(5));
+}
+
+#[test]
+fn repeat_dynamic_count() {
+    let mut value = 0;
+    let repeat = RepeatDynamic {
+        instr: Counter::bump_by(3).into_opt(),
+        count: 4,
+    };
+    assert_eq!(repeat.call(&mut value), Some(12));
+    let repeat = RepeatDynamic {
+        instr: Counter::bump_by(3),
+        count: 0,
+    };
+    assert_eq!(repeat.call(&mut value), None);
+    assert_eq!(value, 12);
+}
+
+#[test]
+fn conditional_guards_call() {
+    let below_ten = |value: &i64| *value < 10;
+    let mut value = 3;
+    let guarded = Conditional(Counter::double(), below_ten);
+    assert_eq!(guarded.call(&mut value), 6);
+    assert_eq!(guarded.call(&mut value), 12);
+    assert_eq!(guarded.call(&mut value), 0);
+    assert_eq!(value, 12);
+    let guarded = Conditional(Counter::bump_by(1).into_opt(), below_ten);
+    assert_eq!(guarded.call(&mut value), 0);
+    assert_eq!(value, 12);
+}
+
+#[test]
+fn map_output_transforms_output() {
+    let mut value = 1;
+    let mapped = Counter::bump_by(2).map_output(|value| value * 10);
+    assert_eq!(mapped.call(&mut value), 30);
+    assert_eq!(value, 3);
+    let mapped = MapOutput(Counter::double().into_opt(), |value: i64| value.to_string());
+    assert_eq!(mapped.call(&mut value), "6");
+    assert_eq!(value, 6);
+    assert_eq!(Literal::constant(4).map_output(|value| value + 1).call(), 5);
+}
+
+#[test]
+fn instrumented_calls_hooks() {
+    let calls = Cell::new(0);
+    let outputs = Cell::new(0);
+    let mut value = 1;
+    let instrumented = Counter::bump_by(2).instrument(
+        |_, value| {
+            assert_eq!(*value, 1);
+            calls.set(calls.get() + 1);
+        },
+        |_, value, output| {
+            assert_eq!(*value, 3);
+            outputs.set(outputs.get() + *output);
+        },
+    );
+    assert_eq!(instrumented.call(&mut value), 3);
+    assert_eq!((calls.get(), outputs.get()), (1, 3));
+    let instrumented = Instrumented {
+        inner: Counter::double().into_opt(),
+        pre: |_: &_, _: &mut i64| calls.set(calls.get() + 1),
+        post: |_: &_, _: &mut i64, output: &i64| outputs.set(outputs.get() + *output),
+    };
+    assert_eq!(instrumented.call(&mut value), 6);
+    assert_eq!((calls.get(), outputs.get()), (2, 9));
+}
+
+#[test]
+fn with_context_calls_with_owned_context() {
+    let mut contexted = Counter::bump_by(2).into_opt().with_context(1);
+    assert_eq!(contexted.call_mut(), 3);
+    assert_eq!(contexted.call_mut(), 5);
+    assert_eq!(contexted.clone().call(), 7);
+    assert_eq!(contexted.clone().into_context(), 5);
+    let mut callbacks: Vec<Box<dyn FnMut() -> i64>> = vec![
+        Box::new(contexted.into_fn()),
+        Box::new(Counter::double().with_context(3).into_fn()),
+    ];
+    assert_eq!(
+        callbacks.iter_mut().map(|f| f()).collect::<Vec<_>>(),
+        [7, 6]
+    );
+    assert_eq!(
+        callbacks.iter_mut().map(|f| f()).collect::<Vec<_>>(),
+        [9, 12]
+    );
+}
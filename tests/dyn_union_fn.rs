@@ -0,0 +1,43 @@
+#![cfg(feature = "alloc")]
+
+use union_fn::{union_fn, CallWithContext as _, DynUnionFn, IntoOpt as _};
+
+#[union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+#[union_fn]
+trait Extension {
+    type Context = i64;
+
+    /// Squares the value.
+    fn square(value: &mut Self::Context) {
+        *value *= *value;
+    }
+}
+
+type CounterOpt = <Counter as union_fn::IntoOpt>::Opt;
+
+#[test]
+fn mixes_static_and_dynamic_instructions() {
+    let instrs: Vec<DynUnionFn<CounterOpt>> = vec![
+        Counter::bump_by(2).into_opt().into(),
+        DynUnionFn::dynamic(Extension::square().into_opt()),
+        DynUnionFn::dynamic(Counter::bump_by(-1)),
+    ];
+    let mut value = 1;
+    for instr in &instrs {
+        instr.call_ref(&mut value);
+    }
+    assert_eq!(value, 8);
+    for instr in instrs {
+        instr.call(&mut value);
+    }
+    assert_eq!(value, 99);
+}
@@ -0,0 +1,172 @@
+use core::ops::ControlFlow;
+use union_fn::{
+    by_variant, execute_slice, fold_instructions, union_fn, CallWithContext as _, ContextSnapshot,
+    InstructionSlice, IntoOpt as _,
+};
+
+#[union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Trap {
+    DivisionByZero,
+}
+
+#[union_fn]
+trait Instr {
+    type Context = i64;
+    type Output = Result<ControlFlow<i64>, Trap>;
+
+    /// Adds `rhs` to the value.
+    fn add(value: &mut Self::Context, rhs: i64) -> Self::Output {
+        *value += rhs;
+        Ok(ControlFlow::Continue(()))
+    }
+
+    /// Divides the value by `rhs`.
+    fn div(value: &mut Self::Context, rhs: i64) -> Self::Output {
+        if rhs == 0 {
+            return Err(Trap::DivisionByZero);
+        }
+        *value /= rhs;
+        Ok(ControlFlow::Continue(()))
+    }
+
+    /// Returns the value.
+    fn ret(value: &mut Self::Context) -> Self::Output {
+        Ok(ControlFlow::Break(*value))
+    }
+}
+
+#[test]
+fn execute_unit_output() {
+    let mut value = 0;
+    let instrs = [
+        Counter::bump_by(1),
+        Counter::bump_by(2),
+        Counter::bump_by(3),
+    ];
+    execute_slice(&instrs, &mut value);
+    assert_eq!(value, 6);
+    let instrs = instrs.map(|instr| instr.into_opt());
+    execute_slice(&instrs, &mut value);
+    assert_eq!(value, 12);
+}
+
+#[test]
+fn execute_stops_on_break() {
+    let mut value = 0;
+    let instrs = [Instr::add(10), Instr::ret(), Instr::add(1)];
+    assert_eq!(
+        execute_slice(&instrs, &mut value),
+        Ok(ControlFlow::Break(10))
+    );
+    assert_eq!(value, 10);
+}
+
+#[test]
+fn execute_stops_on_error() {
+    let mut value = 0;
+    let instrs = [Instr::add(10), Instr::div(0), Instr::add(1)].map(|instr| instr.into_opt());
+    assert_eq!(
+        execute_slice(&instrs, &mut value),
+        Err(Trap::DivisionByZero)
+    );
+    assert_eq!(value, 10);
+}
+
+#[test]
+fn execute_without_break() {
+    let mut value = 0;
+    let instrs = [Instr::add(10), Instr::div(2)];
+    assert_eq!(
+        execute_slice(&instrs, &mut value),
+        Ok(ControlFlow::Continue(()))
+    );
+    assert_eq!(value, 5);
+    assert_eq!(
+        execute_slice::<Instr, _, _>(&[], &mut value),
+        Ok(ControlFlow::Continue(()))
+    );
+}
+
+#[test]
+fn instruction_slice() {
+    let instrs = [Instr::add(10), Instr::div(2), Instr::ret(), Instr::add(1)];
+    let slice = InstructionSlice::new(&instrs);
+    assert_eq!(slice.len(), 4);
+    assert!(!slice.is_empty());
+    assert!(slice.get(2).is_some_and(|instr| instr.is_ret()));
+    assert!(slice.get(4).is_none());
+    assert_eq!(slice.iter().filter(|instr| instr.is_add()).count(), 2);
+    let mut value = 0;
+    assert_eq!(slice.execute(&mut value), Ok(ControlFlow::Break(5)));
+    assert_eq!(value, 5);
+
+    let empty = InstructionSlice::<Instr>::from(&[][..]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.execute(&mut value), Ok(ControlFlow::Continue(())));
+}
+
+#[test]
+fn fold_collects_all_outputs() {
+    let mut value = 0;
+    let instrs = [Instr::add(10), Instr::div(0), Instr::add(5), Instr::div(0)];
+    let traps = fold_instructions(&instrs, &mut value, 0, |traps, output| match output {
+        Err(Trap::DivisionByZero) => traps + 1,
+        Ok(_) => traps,
+    });
+    assert_eq!(traps, 2);
+    assert_eq!(value, 15);
+
+    let instrs = [Counter::bump_by(1), Counter::bump_by(2)].map(|instr| instr.into_opt());
+    let calls = fold_instructions(&instrs, &mut value, 0, |calls, ()| calls + 1);
+    assert_eq!(calls, 2);
+    assert_eq!(value, 18);
+}
+
+#[test]
+fn snapshot_restores_on_failure() {
+    let mut value = 10;
+    let instrs = [Instr::add(5), Instr::div(0)].map(|instr| instr.into_opt());
+    for instr in instrs {
+        let snapshot = ContextSnapshot::save(&mut value);
+        if instr.call(&mut value).is_err() {
+            snapshot.restore(&mut value);
+        }
+    }
+    assert_eq!(value, 15);
+    let snapshot = ContextSnapshot::save(&mut value);
+    assert_eq!(
+        execute_slice(&instrs, &mut value),
+        Err(Trap::DivisionByZero)
+    );
+    assert_eq!(value, 20);
+    snapshot.restore(&mut value);
+    assert_eq!(value, 15);
+}
+
+#[test]
+fn by_variant_filters_instructions() {
+    let instrs = [Instr::add(1), Instr::div(2), Instr::add(3), Instr::ret()];
+    let adds: Vec<_> = by_variant(&instrs, Instr::add(0).discriminant()).collect();
+    assert_eq!(adds.len(), 2);
+    assert!(adds.iter().all(|instr| instr.is_add()));
+    let counts: Vec<_> = Instr::variants()
+        .iter()
+        .map(|variant| {
+            (
+                variant.name,
+                by_variant(&instrs, variant.discriminant).count(),
+            )
+        })
+        .collect();
+    assert_eq!(counts, [("add", 2), ("div", 1), ("ret", 1)]);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "alloc")]
+
+use union_fn::{execute_slice, union_fn, InstructionBuilder};
+
+#[union_fn]
+trait Instr {
+    type Context = Vec<i64>;
+    type Output = core::ops::ControlFlow<i64>;
+
+    /// Pushes the constant `value` onto the stack.
+    fn constant(stack: &mut Self::Context, value: i64) -> Self::Output {
+        stack.push(value);
+        core::ops::ControlFlow::Continue(())
+    }
+
+    /// Pops two values and pushes their sum onto the stack.
+    fn add(stack: &mut Self::Context) -> Self::Output {
+        let rhs = stack.pop().unwrap();
+        let lhs = stack.pop().unwrap();
+        stack.push(lhs + rhs);
+        core::ops::ControlFlow::Continue(())
+    }
+
+    /// Returns the value on top of the stack.
+    fn ret(stack: &mut Self::Context) -> Self::Output {
+        core::ops::ControlFlow::Break(stack.pop().unwrap())
+    }
+}
+
+#[test]
+fn builds_instruction_sequence() {
+    let builder = InstructionBuilder::new()
+        .push(Instr::constant(40))
+        .extend([Instr::constant(2), Instr::add()]);
+    assert_eq!(builder.len(), 3);
+    let instrs = builder.push(Instr::ret()).build();
+    assert_eq!(instrs.len(), 4);
+    let result = execute_slice(&instrs, &mut Vec::new());
+    assert_eq!(result, core::ops::ControlFlow::Break(42));
+}
+
+#[test]
+fn default_builder_is_empty() {
+    let builder = InstructionBuilder::<Instr>::default();
+    assert!(builder.is_empty());
+    assert!(builder.build().is_empty());
+}
@@ -0,0 +1,40 @@
+//! Checks that lint attributes on `#[union_fn]` methods are forwarded
+//! to the generated functions so that `cargo clippy` stays silent.
+
+#![deny(clippy::all)]
+
+use union_fn::{union_fn, Call as _, IntoOpt as _};
+
+#[union_fn(warn_args_size = 128)]
+trait Summer {
+    /// Sums all parameters.
+    #[allow(clippy::too_many_arguments)]
+    fn sum17(
+        p0: i32,
+        p1: i32,
+        p2: i32,
+        p3: i32,
+        p4: i32,
+        p5: i32,
+        p6: i32,
+        p7: i32,
+        p8: i32,
+        p9: i32,
+        p10: i32,
+        p11: i32,
+        p12: i32,
+        p13: i32,
+        p14: i32,
+        p15: i32,
+        p16: i32,
+    ) -> i32 {
+        p0 + p1 + p2 + p3 + p4 + p5 + p6 + p7 + p8 + p9 + p10 + p11 + p12 + p13 + p14 + p15 + p16
+    }
+}
+
+#[test]
+fn many_args_sum() {
+    let instr = Summer::sum17(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17);
+    assert_eq!(instr.call(), 153);
+    assert_eq!(instr.into_opt().call(), 153);
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "alloc")]
+#![allow(clippy::large_enum_variant)]
+
+use union_fn::{union_fn, CallWithContext as _, IntoOpt as _, OptBoxed};
+
+#[union_fn(warn_args_size = 512)]
+trait Program {
+    type Context = u64;
+
+    /// Sums all words of the `bytecode` into the checksum.
+    fn load(checksum: &mut Self::Context, bytecode: [u64; 32]) {
+        *checksum = bytecode.iter().sum();
+    }
+
+    /// Resets the checksum to zero.
+    fn reset(checksum: &mut Self::Context) {
+        *checksum = 0;
+    }
+}
+
+#[test]
+fn boxed_opt_is_small() {
+    let load = OptBoxed::new(Program::load([1; 32]).into_opt());
+    assert!(core::mem::size_of_val(&load) < core::mem::size_of::<[u64; 32]>());
+    let mut checksum = 0;
+    load.clone().call(&mut checksum);
+    assert_eq!(checksum, 32);
+    OptBoxed::new(Program::reset().into_opt()).call(&mut checksum);
+    assert_eq!(checksum, 0);
+    load.into_inner().call(&mut checksum);
+    assert_eq!(checksum, 32);
+}
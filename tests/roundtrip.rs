@@ -0,0 +1,102 @@
+//! Checks that calling an instruction directly and via its call optimized
+//! type yields identical results for randomly generated instructions.
+
+#![cfg(feature = "arbitrary")]
+
+use union_fn::{
+    arbitrary::{Arbitrary, Unstructured},
+    union_fn, CallWithContext as _, IntoOpt as _,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    regs: [i64; 4],
+    flag: bool,
+}
+
+#[union_fn(arbitrary)]
+trait Instr {
+    type Context = Machine;
+    type Output = Result<i64, &'static str>;
+
+    /// Sets register `dst` to the `value`.
+    fn constant(m: &mut Self::Context, dst: u8, value: i64) -> Self::Output {
+        let dst = usize::from(dst % 4);
+        m.regs[dst] = value;
+        Ok(value)
+    }
+
+    /// Adds registers `lhs` and `rhs` into register `dst`.
+    fn add(m: &mut Self::Context, dst: u8, lhs: u8, rhs: u8) -> Self::Output {
+        let value = m.regs[usize::from(lhs % 4)].wrapping_add(m.regs[usize::from(rhs % 4)]);
+        m.regs[usize::from(dst % 4)] = value;
+        Ok(value)
+    }
+
+    /// Divides register `dst` by `divisor`.
+    fn div(m: &mut Self::Context, dst: u8, divisor: i32) -> Self::Output {
+        let dst = usize::from(dst % 4);
+        let value = m.regs[dst]
+            .checked_div(i64::from(divisor))
+            .ok_or("division failed")?;
+        m.regs[dst] = value;
+        Ok(value)
+    }
+
+    /// Sets the flag if register `src` is negative.
+    fn test_neg(m: &mut Self::Context, src: u8) -> Self::Output {
+        m.flag = m.regs[usize::from(src % 4)] < 0;
+        Ok(i64::from(m.flag))
+    }
+
+    /// Selects between `lhs` and `rhs` depending on the flag.
+    fn select(m: &mut Self::Context, dst: u8, lhs: i16, rhs: i16) -> Self::Output {
+        let value = i64::from(if m.flag { lhs } else { rhs });
+        m.regs[usize::from(dst % 4)] = value;
+        Ok(value)
+    }
+
+    /// Clears all registers and the flag.
+    fn clear(m: &mut Self::Context) -> Self::Output {
+        *m = Machine {
+            regs: [0; 4],
+            flag: false,
+        };
+        Ok(0)
+    }
+}
+
+/// Returns `len` pseudo random bytes derived from `seed`.
+fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn enum_and_opt_call_agree() {
+    for seed in 0..500 {
+        let bytes = random_bytes(seed, 1024);
+        let mut u = Unstructured::new(&bytes);
+        let mut lhs = Machine {
+            regs: <[i64; 4]>::arbitrary(&mut u).unwrap(),
+            flag: bool::arbitrary(&mut u).unwrap(),
+        };
+        let mut rhs = lhs.clone();
+        while let Ok(instr) = Instr::arbitrary(&mut u) {
+            if u.is_empty() {
+                break;
+            }
+            let expected = instr.call(&mut lhs);
+            let actual = instr.into_opt().call(&mut rhs);
+            assert_eq!(expected, actual, "seed {seed}: {instr}");
+            assert_eq!(lhs, rhs, "seed {seed}: {instr}");
+        }
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "serde")]
+
+use union_fn::{union_fn, CallWithContext as _};
+
+#[union_fn(serde)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects the values in `choices` depending on `value`.
+    fn select(value: &mut Self::Context, choices: [i64; 4]) {
+        *value = choices.get(*value as usize).copied().unwrap_or(0)
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn execute(instrs: &[Counter]) -> i64 {
+    let mut value = 0;
+    for instr in instrs {
+        instr.call(&mut value);
+    }
+    value
+}
+
+#[test]
+fn serde_json_roundtrip() {
+    let instrs = vec![
+        Counter::bump_by(2),
+        Counter::select([11, 22, 33, 44]),
+        Counter::bump_by(-3),
+        Counter::reset(),
+        Counter::bump_by(5),
+    ];
+    let json = serde_json::to_string(&instrs).unwrap();
+    let decoded = serde_json::from_str::<Vec<Counter>>(&json).unwrap();
+    assert_eq!(execute(&decoded), execute(&instrs));
+    assert_eq!(
+        decoded
+            .iter()
+            .map(Counter::variant_name)
+            .collect::<Vec<_>>(),
+        instrs.iter().map(Counter::variant_name).collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn serde_json_uses_method_names() {
+    let json = serde_json::to_string(&Counter::bump_by(42)).unwrap();
+    assert_eq!(json, r#"{"bump_by":{"by":42}}"#);
+    let json = serde_json::to_string(&Counter::reset()).unwrap();
+    assert_eq!(json, r#"{"reset":{}}"#);
+}
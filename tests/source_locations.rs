@@ -0,0 +1,55 @@
+#![cfg(feature = "source_locations")]
+
+use union_fn::{union_fn, CallWithContext as _, IntoOpt as _};
+
+#[union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, #[default(1)] by: i64) {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+#[test]
+fn into_opt_location() {
+    let line = line!() + 1;
+    let opt = Counter::bump_by(5).into_opt();
+    assert_eq!(opt.location().file(), file!());
+    assert_eq!(opt.location().line(), line);
+    let mut value = 0;
+    opt.call(&mut value);
+    assert_eq!(value, 5);
+}
+
+#[test]
+fn from_location() {
+    let line = line!() + 1;
+    let opt = <Counter as union_fn::IntoOpt>::Opt::from(Counter::reset());
+    assert_eq!(opt.location().line(), line);
+}
+
+#[test]
+fn opt_constructor_location() {
+    let line = line!() + 1;
+    let opt = <Counter as union_fn::IntoOpt>::Opt::bump_by_default();
+    assert_eq!(opt.location().line(), line);
+    let mut value = 0;
+    opt.call(&mut value);
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn opt_debug() {
+    let line = line!() + 1;
+    let opt = Counter::reset().into_opt();
+    let debug = format!("{opt:?}");
+    let location = format!("location: {}:{line}:", file!());
+    assert!(debug.contains(&location), "{debug}");
+}
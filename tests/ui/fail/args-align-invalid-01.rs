@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(args_align = 12)]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(args_repr = "packed")]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,8 @@
+fn main() {}
+
+#[union_fn::union_fn]
+trait Foo {
+    const MAX_DEPTH: usize;
+
+    fn foo() {}
+}
@@ -0,0 +1,19 @@
+#![deny(unused_must_use)]
+
+use union_fn::IntoOpt as _;
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+fn main() {
+    Counter::bump_by(1);
+    <Counter as union_fn::IntoOpt>::Opt::bump_by(1);
+    let _ = Counter::bump_by(1).into_opt();
+}
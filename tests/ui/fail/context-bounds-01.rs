@@ -0,0 +1,12 @@
+use std::rc::Rc;
+
+#[union_fn::union_fn(context_bounds = "Send")]
+trait Counter {
+    type Context = Rc<i64>;
+
+    fn reset(value: &mut Self::Context) {
+        *value = Rc::new(0);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[union_fn::union_fn(context_bounds = "Send")]
+trait Counter {
+    type Output = i64;
+
+    fn constant(value: i64) -> Self::Output {
+        value
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    fn bump_by(value: &mut Self::Context, #[default(1)] by: i64) {
+        *value += by;
+    }
+
+    fn bump_by_default(value: &mut Self::Context) {
+        *value += 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[union_fn::union_fn]
+trait Instr {
+    type Context = i64;
+
+    #[union_fn(name = "i64.add")]
+    fn i64_add(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = lhs + rhs;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,262 @@
+fn main() {}
+
+#[union_fn::union_fn(enum_repr = "u8")]
+trait Foo {
+    fn f0() {}
+    fn f1() {}
+    fn f2() {}
+    fn f3() {}
+    fn f4() {}
+    fn f5() {}
+    fn f6() {}
+    fn f7() {}
+    fn f8() {}
+    fn f9() {}
+    fn f10() {}
+    fn f11() {}
+    fn f12() {}
+    fn f13() {}
+    fn f14() {}
+    fn f15() {}
+    fn f16() {}
+    fn f17() {}
+    fn f18() {}
+    fn f19() {}
+    fn f20() {}
+    fn f21() {}
+    fn f22() {}
+    fn f23() {}
+    fn f24() {}
+    fn f25() {}
+    fn f26() {}
+    fn f27() {}
+    fn f28() {}
+    fn f29() {}
+    fn f30() {}
+    fn f31() {}
+    fn f32() {}
+    fn f33() {}
+    fn f34() {}
+    fn f35() {}
+    fn f36() {}
+    fn f37() {}
+    fn f38() {}
+    fn f39() {}
+    fn f40() {}
+    fn f41() {}
+    fn f42() {}
+    fn f43() {}
+    fn f44() {}
+    fn f45() {}
+    fn f46() {}
+    fn f47() {}
+    fn f48() {}
+    fn f49() {}
+    fn f50() {}
+    fn f51() {}
+    fn f52() {}
+    fn f53() {}
+    fn f54() {}
+    fn f55() {}
+    fn f56() {}
+    fn f57() {}
+    fn f58() {}
+    fn f59() {}
+    fn f60() {}
+    fn f61() {}
+    fn f62() {}
+    fn f63() {}
+    fn f64() {}
+    fn f65() {}
+    fn f66() {}
+    fn f67() {}
+    fn f68() {}
+    fn f69() {}
+    fn f70() {}
+    fn f71() {}
+    fn f72() {}
+    fn f73() {}
+    fn f74() {}
+    fn f75() {}
+    fn f76() {}
+    fn f77() {}
+    fn f78() {}
+    fn f79() {}
+    fn f80() {}
+    fn f81() {}
+    fn f82() {}
+    fn f83() {}
+    fn f84() {}
+    fn f85() {}
+    fn f86() {}
+    fn f87() {}
+    fn f88() {}
+    fn f89() {}
+    fn f90() {}
+    fn f91() {}
+    fn f92() {}
+    fn f93() {}
+    fn f94() {}
+    fn f95() {}
+    fn f96() {}
+    fn f97() {}
+    fn f98() {}
+    fn f99() {}
+    fn f100() {}
+    fn f101() {}
+    fn f102() {}
+    fn f103() {}
+    fn f104() {}
+    fn f105() {}
+    fn f106() {}
+    fn f107() {}
+    fn f108() {}
+    fn f109() {}
+    fn f110() {}
+    fn f111() {}
+    fn f112() {}
+    fn f113() {}
+    fn f114() {}
+    fn f115() {}
+    fn f116() {}
+    fn f117() {}
+    fn f118() {}
+    fn f119() {}
+    fn f120() {}
+    fn f121() {}
+    fn f122() {}
+    fn f123() {}
+    fn f124() {}
+    fn f125() {}
+    fn f126() {}
+    fn f127() {}
+    fn f128() {}
+    fn f129() {}
+    fn f130() {}
+    fn f131() {}
+    fn f132() {}
+    fn f133() {}
+    fn f134() {}
+    fn f135() {}
+    fn f136() {}
+    fn f137() {}
+    fn f138() {}
+    fn f139() {}
+    fn f140() {}
+    fn f141() {}
+    fn f142() {}
+    fn f143() {}
+    fn f144() {}
+    fn f145() {}
+    fn f146() {}
+    fn f147() {}
+    fn f148() {}
+    fn f149() {}
+    fn f150() {}
+    fn f151() {}
+    fn f152() {}
+    fn f153() {}
+    fn f154() {}
+    fn f155() {}
+    fn f156() {}
+    fn f157() {}
+    fn f158() {}
+    fn f159() {}
+    fn f160() {}
+    fn f161() {}
+    fn f162() {}
+    fn f163() {}
+    fn f164() {}
+    fn f165() {}
+    fn f166() {}
+    fn f167() {}
+    fn f168() {}
+    fn f169() {}
+    fn f170() {}
+    fn f171() {}
+    fn f172() {}
+    fn f173() {}
+    fn f174() {}
+    fn f175() {}
+    fn f176() {}
+    fn f177() {}
+    fn f178() {}
+    fn f179() {}
+    fn f180() {}
+    fn f181() {}
+    fn f182() {}
+    fn f183() {}
+    fn f184() {}
+    fn f185() {}
+    fn f186() {}
+    fn f187() {}
+    fn f188() {}
+    fn f189() {}
+    fn f190() {}
+    fn f191() {}
+    fn f192() {}
+    fn f193() {}
+    fn f194() {}
+    fn f195() {}
+    fn f196() {}
+    fn f197() {}
+    fn f198() {}
+    fn f199() {}
+    fn f200() {}
+    fn f201() {}
+    fn f202() {}
+    fn f203() {}
+    fn f204() {}
+    fn f205() {}
+    fn f206() {}
+    fn f207() {}
+    fn f208() {}
+    fn f209() {}
+    fn f210() {}
+    fn f211() {}
+    fn f212() {}
+    fn f213() {}
+    fn f214() {}
+    fn f215() {}
+    fn f216() {}
+    fn f217() {}
+    fn f218() {}
+    fn f219() {}
+    fn f220() {}
+    fn f221() {}
+    fn f222() {}
+    fn f223() {}
+    fn f224() {}
+    fn f225() {}
+    fn f226() {}
+    fn f227() {}
+    fn f228() {}
+    fn f229() {}
+    fn f230() {}
+    fn f231() {}
+    fn f232() {}
+    fn f233() {}
+    fn f234() {}
+    fn f235() {}
+    fn f236() {}
+    fn f237() {}
+    fn f238() {}
+    fn f239() {}
+    fn f240() {}
+    fn f241() {}
+    fn f242() {}
+    fn f243() {}
+    fn f244() {}
+    fn f245() {}
+    fn f246() {}
+    fn f247() {}
+    fn f248() {}
+    fn f249() {}
+    fn f250() {}
+    fn f251() {}
+    fn f252() {}
+    fn f253() {}
+    fn f254() {}
+    fn f255() {}
+    fn f256() {}
+}
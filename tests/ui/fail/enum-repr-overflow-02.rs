@@ -0,0 +1,134 @@
+fn main() {}
+
+#[union_fn::union_fn(enum_repr = "i8")]
+trait Foo {
+    fn f0() {}
+    fn f1() {}
+    fn f2() {}
+    fn f3() {}
+    fn f4() {}
+    fn f5() {}
+    fn f6() {}
+    fn f7() {}
+    fn f8() {}
+    fn f9() {}
+    fn f10() {}
+    fn f11() {}
+    fn f12() {}
+    fn f13() {}
+    fn f14() {}
+    fn f15() {}
+    fn f16() {}
+    fn f17() {}
+    fn f18() {}
+    fn f19() {}
+    fn f20() {}
+    fn f21() {}
+    fn f22() {}
+    fn f23() {}
+    fn f24() {}
+    fn f25() {}
+    fn f26() {}
+    fn f27() {}
+    fn f28() {}
+    fn f29() {}
+    fn f30() {}
+    fn f31() {}
+    fn f32() {}
+    fn f33() {}
+    fn f34() {}
+    fn f35() {}
+    fn f36() {}
+    fn f37() {}
+    fn f38() {}
+    fn f39() {}
+    fn f40() {}
+    fn f41() {}
+    fn f42() {}
+    fn f43() {}
+    fn f44() {}
+    fn f45() {}
+    fn f46() {}
+    fn f47() {}
+    fn f48() {}
+    fn f49() {}
+    fn f50() {}
+    fn f51() {}
+    fn f52() {}
+    fn f53() {}
+    fn f54() {}
+    fn f55() {}
+    fn f56() {}
+    fn f57() {}
+    fn f58() {}
+    fn f59() {}
+    fn f60() {}
+    fn f61() {}
+    fn f62() {}
+    fn f63() {}
+    fn f64() {}
+    fn f65() {}
+    fn f66() {}
+    fn f67() {}
+    fn f68() {}
+    fn f69() {}
+    fn f70() {}
+    fn f71() {}
+    fn f72() {}
+    fn f73() {}
+    fn f74() {}
+    fn f75() {}
+    fn f76() {}
+    fn f77() {}
+    fn f78() {}
+    fn f79() {}
+    fn f80() {}
+    fn f81() {}
+    fn f82() {}
+    fn f83() {}
+    fn f84() {}
+    fn f85() {}
+    fn f86() {}
+    fn f87() {}
+    fn f88() {}
+    fn f89() {}
+    fn f90() {}
+    fn f91() {}
+    fn f92() {}
+    fn f93() {}
+    fn f94() {}
+    fn f95() {}
+    fn f96() {}
+    fn f97() {}
+    fn f98() {}
+    fn f99() {}
+    fn f100() {}
+    fn f101() {}
+    fn f102() {}
+    fn f103() {}
+    fn f104() {}
+    fn f105() {}
+    fn f106() {}
+    fn f107() {}
+    fn f108() {}
+    fn f109() {}
+    fn f110() {}
+    fn f111() {}
+    fn f112() {}
+    fn f113() {}
+    fn f114() {}
+    fn f115() {}
+    fn f116() {}
+    fn f117() {}
+    fn f118() {}
+    fn f119() {}
+    fn f120() {}
+    fn f121() {}
+    fn f122() {}
+    fn f123() {}
+    fn f124() {}
+    fn f125() {}
+    fn f126() {}
+    fn f127() {}
+    fn f128() {}
+}
@@ -0,0 +1,14 @@
+pub struct Machine {
+    accumulator: i64,
+}
+
+#[union_fn::union_fn(ffi)]
+trait Counter {
+    type Context = Machine;
+
+    fn bump_by(machine: &mut Self::Context, by: i64) {
+        machine.accumulator += by;
+    }
+}
+
+fn main() {}
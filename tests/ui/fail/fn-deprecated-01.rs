@@ -0,0 +1,24 @@
+#![deny(deprecated)]
+
+use union_fn::IntoOpt as _;
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Bumps the value by one.
+    #[deprecated(since = "2.0", note = "use bump_by instead")]
+    fn inc(value: &mut Self::Context) {
+        *value += 1;
+    }
+}
+
+fn main() {
+    let _ = Counter::bump_by(1).into_opt();
+    let _ = Counter::inc();
+}
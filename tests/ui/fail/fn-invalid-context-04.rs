@@ -0,0 +1,10 @@
+fn main() {}
+
+#[union_fn::union_fn]
+trait Foo {
+    type Context = i32;
+
+    fn foo(ctx: i32) {}
+    fn bar(ctx: &mut i64) {}
+    fn baz(ctx: *const Self::Context) {}
+}
@@ -0,0 +1,9 @@
+fn main() {}
+
+#[union_fn::union_fn]
+trait Foo {
+    fn foo() -> i32 {
+        0
+    }
+    fn bar() {}
+}
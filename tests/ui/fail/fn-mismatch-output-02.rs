@@ -0,0 +1,12 @@
+fn main() {}
+
+#[union_fn::union_fn]
+trait Foo {
+    fn foo() -> i32 {
+        0
+    }
+
+    fn bar() -> i64 {
+        1
+    }
+}
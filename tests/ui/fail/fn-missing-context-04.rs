@@ -0,0 +1,8 @@
+fn main() {}
+
+#[union_fn::union_fn]
+trait Foo {
+    type Context = i32;
+
+    fn foo(value: i64) {}
+}
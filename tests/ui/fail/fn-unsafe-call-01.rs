@@ -0,0 +1,22 @@
+use union_fn::{IntoOpt as _, UnsafeCall as _};
+
+static DATA: [u8; 4] = [1, 2, 3, 5];
+
+#[union_fn::union_fn]
+trait Read {
+    type Output = u8;
+
+    /// Reads the byte at `index` of [`DATA`] without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The `index` must be in bounds of [`DATA`].
+    unsafe fn read(index: usize) -> Self::Output {
+        *DATA.get_unchecked(index)
+    }
+}
+
+fn main() {
+    Read::read(3).call();
+    Read::read(3).into_opt().call();
+}
@@ -0,0 +1,7 @@
+fn main() {}
+
+#[union_fn::union_fn]
+trait Foo {
+    fn foo() {}
+    fn as_foo() {}
+}
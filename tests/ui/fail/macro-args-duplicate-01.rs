@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(enum_repr = "u8", enum_repr = "u16")]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(unknown)]
+trait Foo {
+    fn foo() {}
+}
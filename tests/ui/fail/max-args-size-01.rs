@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(max_args_size = 16)]
+trait Foo {
+    fn foo(_bytes: [u8; 32]) {}
+}
@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(max_args_size = "many")]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,7 @@
+fn main() {}
+
+#[union_fn::union_fn(max_variants = 1)]
+trait Foo {
+    fn foo() {}
+    fn bar() {}
+}
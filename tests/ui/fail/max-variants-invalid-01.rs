@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(max_variants = "many")]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,11 @@
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    #[named_args(by)]
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    #[named_args]
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {}
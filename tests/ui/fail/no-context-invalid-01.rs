@@ -0,0 +1,11 @@
+#[union_fn::union_fn]
+trait Calc {
+    type Context = i32;
+
+    #[no_context(a)]
+    fn multiply(a: i32, b: i32) -> i32 {
+        a * b
+    }
+}
+
+fn main() {}
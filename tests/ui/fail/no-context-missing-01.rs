@@ -0,0 +1,9 @@
+#[union_fn::union_fn]
+trait Calc {
+    #[no_context]
+    fn multiply(a: i32, b: i32) -> i32 {
+        a * b
+    }
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(no_enum = true)]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(no_opt, args_repr = "C")]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,10 @@
+#[union_fn::union_fn(no_opt, ffi)]
+trait Counter {
+    type Context = i64;
+
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+fn main() {}
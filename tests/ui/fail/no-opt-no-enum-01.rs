@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(no_opt, no_enum)]
+trait Foo {
+    fn foo() {}
+}
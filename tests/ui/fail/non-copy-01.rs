@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn]
+trait Foo {
+    fn foo(_message: String) {}
+}
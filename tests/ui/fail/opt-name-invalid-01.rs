@@ -0,0 +1,6 @@
+fn main() {}
+
+#[union_fn::union_fn(opt_name = "Compiled Foo")]
+trait Foo {
+    fn foo() {}
+}
@@ -0,0 +1,6 @@
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+}
+
+fn main() {}
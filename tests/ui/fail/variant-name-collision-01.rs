@@ -0,0 +1,15 @@
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    fn foo_bar(value: &mut Self::Context) {
+        *value += 1;
+    }
+
+    #[allow(non_snake_case)]
+    fn FooBar(value: &mut Self::Context) {
+        *value += 2;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#![deny(deprecated)]
+
+#[union_fn::union_fn(warn_args_size = 8)]
+trait Foo {
+    fn small(_value: u8) {}
+    fn large(_values: [u64; 2]) {}
+}
+
+fn main() {}
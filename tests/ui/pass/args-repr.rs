@@ -0,0 +1,35 @@
+use union_fn::{CallWithContext as _, UnionFn};
+
+#[union_fn::union_fn(args_repr = "C", args_align = 16)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+#[union_fn::union_fn(args_align = "32")]
+trait Aligned {
+    fn one() -> i32 {
+        1
+    }
+}
+
+const _: () = assert!(<Counter as UnionFn>::ARGS_SIZE == 16);
+
+fn main() {
+    assert_eq!(core::mem::align_of::<<Counter as UnionFn>::Args>(), 16);
+    assert_eq!(core::mem::size_of::<<Counter as UnionFn>::Args>(), 16);
+    assert_eq!(core::mem::align_of::<<Aligned as UnionFn>::Args>(), 32);
+
+    let mut value = 0;
+    Counter::bump_by(42).call(&mut value);
+    assert_eq!(value, 42);
+}
@@ -0,0 +1,25 @@
+use union_fn::{Call as _, IntoOpt as _, UnionFn as _};
+
+#[union_fn::union_fn(module = "packet_impl")]
+trait Packet {
+    type Output = u32;
+
+    /// Returns the sum of the `values`.
+    fn sum(values: [u32; 3]) -> Self::Output {
+        values.iter().sum()
+    }
+
+    /// Returns the `lhs` plus `rhs`.
+    fn add(lhs: u8, rhs: u16) -> Self::Output {
+        u32::from(lhs) + u32::from(rhs)
+    }
+}
+
+use packet_impl::{PacketArgs, PacketOpt};
+
+fn main() {
+    assert_eq!(PacketArgs::SIZE, 12);
+    assert_eq!(PacketArgs::SIZE, PacketOpt::ARGS_SIZE);
+    assert_eq!(Packet::sum([1, 2, 3]).into_opt().call(), 6);
+    assert_eq!(Packet::add(1, 2).into_opt().call(), 3);
+}
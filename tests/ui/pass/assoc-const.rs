@@ -0,0 +1,50 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// The largest value the counter saturates at.
+    const MAX_VALUE: i64 = 100;
+
+    /// The step used by `step`.
+    const STEP: i64 = Self::MAX_VALUE / 10;
+
+    /// Bumps the value `by` the amount saturating at `MAX_VALUE`.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value = (*value + by).min(Self::MAX_VALUE);
+    }
+
+    /// Bumps the value by `STEP`.
+    fn step(value: &mut Self::Context) {
+        *value += Self::STEP;
+    }
+}
+
+#[union_fn::union_fn(no_enum)]
+trait Scaler {
+    type Context = i64;
+
+    /// The factor used by `scale`.
+    const FACTOR: i64 = 3;
+
+    /// Scales the value by `FACTOR`.
+    fn scale(value: &mut Self::Context) {
+        *value *= Self::FACTOR;
+    }
+}
+
+fn main() {
+    assert_eq!(Counter::MAX_VALUE, 100);
+    assert_eq!(Counter::STEP, 10);
+
+    let mut value = 0;
+    Counter::step().call(&mut value);
+    assert_eq!(value, 10);
+    Counter::bump_by(1000).into_opt().call(&mut value);
+    assert_eq!(value, 100);
+
+    assert_eq!(ScalerOpt::FACTOR, 3);
+    ScalerOpt::scale().call(&mut value);
+    assert_eq!(value, 300);
+}
@@ -0,0 +1,48 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Never compiled since its `#[cfg]` is never satisfied.
+    #[cfg(any())]
+    fn missing(value: &mut Self::Context, by: UndefinedType) {
+        *value = by.into();
+    }
+
+    /// Always compiled since its `#[cfg]` is always satisfied.
+    #[cfg(all())]
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    Counter::bump_by(42).call(&mut value);
+    assert_eq!(value, 42);
+    Counter::reset().into_opt().call(&mut value);
+    assert_eq!(value, 0);
+    assert_eq!(Counter::reset().variant_name(), "reset");
+    let names: Vec<_> = Counter::variants().iter().map(|variant| variant.name).collect();
+    assert_eq!(names, ["bump_by", "reset"]);
+    assert_eq!(Counter::VARIANT_COUNT, 2);
+    assert_eq!(COUNTER_VARIANT_NAMES, ["bump_by", "reset"]);
+    assert_eq!(COUNTER_VARIANT_ARG_SIZES, [8, 0]);
+    assert_eq!(Counter::zero_arg_variants().count(), 1);
+    for (index, variant) in Counter::variants().iter().enumerate() {
+        assert_eq!(variant.discriminant, index);
+    }
+    for instr in [Counter::bump_by(1), Counter::reset()] {
+        let discriminant = instr.discriminant();
+        assert_eq!(COUNTER_VARIANT_NAMES[discriminant], instr.variant_name());
+        assert_eq!(Counter::variants()[discriminant].name, instr.variant_name());
+    }
+    assert_eq!(COUNTER_VARIANT_ARG_SIZES[Counter::reset().discriminant()], 0);
+    assert!(matches!(Counter::from_discriminant(1), Some(Counter::Reset {})));
+}
@@ -0,0 +1,29 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn]
+trait Instr {
+    type Context = i64;
+    type Output = Result<(), &'static str>;
+
+    /// Adds `by` to the value.
+    #[inline(always)]
+    fn add(value: &mut Self::Context, by: i64) -> Self::Output {
+        *value += by;
+        Ok(())
+    }
+
+    /// Traps the execution.
+    #[cold]
+    fn trap(_value: &mut Self::Context) -> Self::Output {
+        Err("trap")
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    assert_eq!(Instr::add(2).call(&mut value), Ok(()));
+    assert_eq!(Instr::add(3).into_opt().call(&mut value), Ok(()));
+    assert_eq!(value, 5);
+    assert_eq!(Instr::trap().call(&mut value), Err("trap"));
+    assert_eq!(Instr::trap().into_opt().call(&mut value), Err("trap"));
+}
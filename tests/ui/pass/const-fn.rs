@@ -0,0 +1,51 @@
+#[union_fn::union_fn]
+trait MathFn {
+    const fn one() -> i32 {
+        1
+    }
+
+    const fn add(lhs: i32, rhs: i32) -> i32 {
+        lhs + rhs
+    }
+
+    const fn select(flag: bool, if_true: i32, if_false: i32) -> i32 {
+        if flag {
+            if_true
+        } else {
+            if_false
+        }
+    }
+}
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    const fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    const fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+const ONE: i32 = MathFn::one().call();
+const SUM: i32 = MathFn::add(2, 3).call();
+const SELECTED: i32 = MathFn::select(false, 10, 20).call();
+const COUNTED: i64 = {
+    let mut value = 0;
+    Counter::bump_by(40).call(&mut value);
+    Counter::bump_by(2).call(&mut value);
+    value
+};
+
+fn main() {
+    use union_fn::{Call as _, IntoOpt as _};
+
+    assert_eq!(ONE, 1);
+    assert_eq!(SUM, 5);
+    assert_eq!(SELECTED, 20);
+    assert_eq!(COUNTED, 42);
+    assert_eq!(MathFn::add(2, 3).into_opt().call(), 5);
+}
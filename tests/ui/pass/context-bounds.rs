@@ -0,0 +1,26 @@
+use union_fn::{CallWithContext, IntoOpt as _};
+
+#[union_fn::union_fn(context_bounds = "Send + Sync")]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+fn assert_context_bounds<T>()
+where
+    T: CallWithContext,
+    T::Context: Send + Sync,
+{
+}
+
+fn main() {
+    assert_context_bounds::<Counter>();
+    let mut value = 0;
+    Counter::bump_by(1).call(&mut value);
+    Counter::bump_by(2).into_opt().call(&mut value);
+    assert_eq!(value, 3);
+}
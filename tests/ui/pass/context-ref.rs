@@ -0,0 +1,58 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[derive(Default)]
+pub struct Registers {
+    regs: [i64; 4],
+    stack: Vec<i64>,
+}
+
+#[union_fn::union_fn]
+trait Instr {
+    type Context = Registers;
+    type Output = Option<i64>;
+
+    /// Sets the register `n` to `value`.
+    fn set(ctx: &mut Self::Context, n: usize, value: i64) -> Self::Output {
+        ctx.regs[n] = value;
+        None
+    }
+
+    /// Reads the register `n` without mutating the context.
+    fn read(ctx: &Self::Context, n: usize) -> Self::Output {
+        Some(ctx.regs[n])
+    }
+
+    /// Pushes the register `n` to the stack.
+    fn push(ctx: &mut Self::Context, n: usize) -> Self::Output {
+        let value = ctx.regs[n];
+        ctx.stack.push(value);
+        None
+    }
+}
+
+#[union_fn::union_fn(no_opt)]
+trait Query {
+    type Context = Registers;
+    type Output = i64;
+
+    /// Returns the sum of all registers.
+    fn sum(regs: &Self::Context) -> Self::Output {
+        regs.regs.iter().sum()
+    }
+
+    /// Returns the register `n`.
+    fn get(ctx: &Self::Context, n: usize) -> Self::Output {
+        ctx.regs[n]
+    }
+}
+
+fn main() {
+    let mut ctx = Registers::default();
+    assert_eq!(Instr::set(1, 42).call(&mut ctx), None);
+    assert_eq!(Instr::read(1).call(&mut ctx), Some(42));
+    assert_eq!(Instr::read(1).into_opt().call(&mut ctx), Some(42));
+    assert_eq!(Instr::push(1).into_opt().call(&mut ctx), None);
+    assert_eq!(ctx.stack, [42]);
+    assert_eq!(Query::sum().call(&mut ctx), 42);
+    assert_eq!(Query::get(1).call(&mut ctx), 42);
+}
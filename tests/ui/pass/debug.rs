@@ -0,0 +1,43 @@
+use union_fn::IntoOpt as _;
+
+/// A type that does not implement `Debug`.
+#[derive(Copy, Clone)]
+pub struct Opaque(i64);
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, rhs: Opaque) {
+        *value = if *value == 0 { lhs } else { rhs.0 };
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {
+    let bump_by = Counter::bump_by(42).into_opt();
+    let select = Counter::select(1, Opaque(2)).into_opt();
+    let reset = Counter::reset().into_opt();
+    unsafe {
+        assert_eq!(format!("{:?}", bump_by.args_ref().debug_with_discriminant(0)), "bump_by(42)");
+        assert_eq!(format!("{:?}", select.args_ref().debug_with_discriminant(1)), "select(1, ..)");
+        assert_eq!(format!("{:?}", reset.args_ref().debug_with_discriminant(2)), "reset");
+        assert_eq!(
+            format!("{:?}", reset.args_ref().debug_with_discriminant(3)),
+            "<invalid discriminant>"
+        );
+    }
+
+    let debug = format!("{:?}", bump_by);
+    assert!(debug.starts_with("CounterOpt { handler: 0x"), "{debug}");
+}
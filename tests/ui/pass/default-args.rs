@@ -0,0 +1,39 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn]
+#[derive(Debug, PartialEq)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, #[default(1)] by: i64) {
+        *value += by;
+    }
+
+    /// Bumps the value `by` the amount `times` times.
+    fn bump_times(value: &mut Self::Context, by: i64, #[default(2 + 1)] times: i64) {
+        *value += by * times;
+    }
+}
+
+#[union_fn::union_fn(no_enum)]
+trait Step {
+    type Context = i64;
+
+    fn step(value: &mut Self::Context, #[default(-1)] by: i64) {
+        *value += by;
+    }
+}
+
+fn main() {
+    assert_eq!(Counter::bump_by_default(), Counter::bump_by(1));
+    assert_eq!(Counter::bump_times_default(5), Counter::bump_times(5, 3));
+
+    let mut value = 0;
+    Counter::bump_by_default().call(&mut value);
+    assert_eq!(value, 1);
+    Counter::bump_times_default(2).into_opt().call(&mut value);
+    assert_eq!(value, 7);
+    StepOpt::step_default().call(&mut value);
+    assert_eq!(value, 6);
+}
@@ -0,0 +1,26 @@
+#[union_fn::union_fn]
+trait Instr {
+    type Context = i64;
+
+    /// Sets the value to `lhs` plus `rhs`.
+    #[union_fn(display_name = "i64.add")]
+    fn i64_add(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = lhs + rhs;
+    }
+
+    /// Resets the value to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {
+    assert_eq!(Instr::i64_add(1, 2).variant_name(), "i64.add");
+    assert_eq!(Instr::reset().variant_name(), "reset");
+    assert_eq!(format!("{}", Instr::i64_add(1, 2)), "i64.add(1, 2)");
+    let parsed: Instr = "i64.add(3, 4)".parse().unwrap();
+    assert_eq!(parsed.variant_name(), "i64.add");
+    assert!("i64_add(3, 4)".parse::<Instr>().is_err());
+    assert_eq!(INSTR_VARIANT_NAMES, ["i64.add", "reset"]);
+    assert_eq!(Instr::variants()[0].name, "i64.add");
+}
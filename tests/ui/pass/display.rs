@@ -0,0 +1,92 @@
+/// A type that does not implement `Display`.
+#[derive(Copy, Clone)]
+pub struct Opaque(i64);
+
+/// A type whose textual representation contains a comma.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Point(i64, i64);
+
+impl core::fmt::Display for Point {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl core::str::FromStr for Point {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .and_then(|s| s.split_once(','))
+            .ok_or(())?;
+        Ok(Self(x.trim().parse().map_err(|_| ())?, y.trim().parse().map_err(|_| ())?))
+    }
+}
+
+#[union_fn::union_fn]
+trait Instr {
+    type Context = i64;
+
+    /// Sets the value to the `constant`.
+    fn constant(value: &mut Self::Context, constant: i64) {
+        *value = constant;
+    }
+
+    /// Sets the value to `lhs` plus `rhs`.
+    fn add(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = lhs + rhs;
+    }
+
+    /// Sets the value to the wrapped value of `opaque`.
+    fn opaque(value: &mut Self::Context, opaque: Opaque) {
+        *value = opaque.0;
+    }
+
+    /// Sets the value to the sum of the coordinates of `point` scaled by `scale`.
+    fn point(value: &mut Self::Context, point: Point, scale: i64) {
+        *value = (point.0 + point.1) * scale;
+    }
+
+    /// Resets the value to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+use union_fn::ParseError;
+
+fn main() {
+    assert_eq!(format!("{}", Instr::constant(42)), "constant(42)");
+    assert_eq!(format!("{}", Instr::add(1, -2)), "add(1, -2)");
+    assert_eq!(format!("{}", Instr::opaque(Opaque(5))), "opaque(..)");
+    assert_eq!(format!("{}", Instr::reset()), "reset()");
+
+    assert_eq!(format!("{}", Instr::point(Point(1, 2), 3)), "point((1, 2), 3)");
+
+    for instr in [
+        Instr::constant(42),
+        Instr::add(1, -2),
+        Instr::point(Point(1, -2), 3),
+        Instr::reset(),
+    ] {
+        let text = instr.to_string();
+        assert_eq!(text.parse::<Instr>().unwrap().to_string(), text);
+    }
+    assert_eq!(" add( 1 ,2 ) ".parse::<Instr>().unwrap().as_add(), Some((1, 2)));
+    assert!("reset()".parse::<Instr>().unwrap().is_reset());
+    assert_eq!("reset".parse::<Instr>().err(), Some(ParseError::InvalidSyntax));
+    assert_eq!("mul(1, 2)".parse::<Instr>().err(), Some(ParseError::UnknownMethod));
+    assert_eq!("add(1)".parse::<Instr>().err(), Some(ParseError::MismatchingArgs));
+    assert_eq!("add(1, 2, 3)".parse::<Instr>().err(), Some(ParseError::MismatchingArgs));
+    assert_eq!("reset(1)".parse::<Instr>().err(), Some(ParseError::MismatchingArgs));
+    assert_eq!("add(1, x)".parse::<Instr>().err(), Some(ParseError::InvalidArg(1)));
+    assert_eq!("opaque(..)".parse::<Instr>().err(), Some(ParseError::InvalidArg(0)));
+    assert_eq!(
+        "point( ( 1 , 2 ) , 3 )".parse::<Instr>().unwrap().as_point(),
+        Some((Point(1, 2), 3))
+    );
+    assert_eq!("point((1, 2))".parse::<Instr>().err(), Some(ParseError::MismatchingArgs));
+    assert_eq!("point((1, 2, 3), 4)".parse::<Instr>().err(), Some(ParseError::InvalidArg(0)));
+}
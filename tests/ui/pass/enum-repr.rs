@@ -0,0 +1,26 @@
+#[union_fn::union_fn(enum_repr = "u8")]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: u8) {
+        *value += i64::from(by);
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn tag_of(instr: &Counter) -> u8 {
+    // SAFETY: `#[repr(u8)]` enums store their discriminant as the first byte.
+    unsafe { *(instr as *const Counter as *const u8) }
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Counter>(), 2);
+    assert_eq!(tag_of(&Counter::bump_by(42)), 0);
+    assert_eq!(tag_of(&Counter::reset()), 1);
+    assert_eq!(usize::from(tag_of(&Counter::reset())), Counter::reset().discriminant());
+}
@@ -0,0 +1,30 @@
+use union_fn::{CallWithContext as _, IntoOpt};
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    extern "C" fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    extern "C" fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+type Impls = <Counter as IntoOpt>::Impls;
+
+fn main() {
+    let _: extern "C" fn(&mut i64, i64) = Impls::bump_by;
+    let _: extern "C" fn(&mut i64) = Impls::reset;
+
+    let mut value = 0;
+    Counter::bump_by(5).call(&mut value);
+    Counter::bump_by(2).into_opt().call(&mut value);
+    assert_eq!(value, 7);
+    Counter::reset().into_opt().call(&mut value);
+    assert_eq!(value, 0);
+}
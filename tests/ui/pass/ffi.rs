@@ -0,0 +1,93 @@
+use union_fn::{Call as _, CallWithContext as _, IntoOpt as _, UnionFn, UnsafeCallWithContext as _};
+
+#[repr(C)]
+#[derive(Default)]
+pub struct Machine {
+    accumulator: i64,
+    steps: u32,
+}
+
+#[union_fn::union_fn(ffi, module = "counter_ffi")]
+trait Counter {
+    type Context = Machine;
+    type Output = i64;
+
+    /// Bumps the accumulator `by` the amount.
+    fn bump_by(machine: &mut Self::Context, by: i64) -> Self::Output {
+        machine.accumulator += by;
+        machine.steps += 1;
+        machine.accumulator
+    }
+
+    /// Adds the sum of the `values` to the accumulator.
+    fn add_all(machine: &mut Self::Context, values: [i16; 4], scale: u8) -> Self::Output {
+        let sum: i64 = values.iter().copied().map(i64::from).sum();
+        machine.accumulator += sum * i64::from(scale);
+        machine.steps += 1;
+        machine.accumulator
+    }
+
+    /// Resets the accumulator to zero.
+    fn reset(machine: &mut Self::Context) -> Self::Output {
+        machine.accumulator = 0;
+        machine.steps += 1;
+        machine.accumulator
+    }
+}
+
+#[union_fn::union_fn(ffi)]
+trait MathFn {
+    const fn add(lhs: i32, rhs: i32) -> i32 {
+        lhs + rhs
+    }
+
+    #[cfg(any())]
+    const fn neg(value: char) -> i32 {
+        -(value as i32)
+    }
+}
+
+#[union_fn::union_fn(ffi)]
+trait Memory {
+    type Context = [u8; 4];
+
+    /// Stores `value` at `index` without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The `index` must be in bounds of the memory.
+    unsafe fn store(memory: &mut Self::Context, index: usize, value: u8) {
+        *memory.get_unchecked_mut(index) = value;
+    }
+}
+
+/// Runs the `program` of `len` instructions the way a C front-end would.
+#[deny(improper_ctypes_definitions)]
+extern "C" fn run(program: *const counter_ffi::CounterOpt, len: usize, machine: *mut Machine) -> i64 {
+    let program = unsafe { core::slice::from_raw_parts(program, len) };
+    let machine = unsafe { &mut *machine };
+    program
+        .iter()
+        .fold(0, |_, instr| instr.call(machine))
+}
+
+fn main() {
+    let program = [
+        Counter::bump_by(2).into_opt(),
+        Counter::add_all([1, 2, 3, 4], 3).into_opt(),
+        Counter::reset().into_opt(),
+        Counter::bump_by(-5).into_opt(),
+    ];
+    let mut machine = Machine::default();
+    let result = run(program.as_ptr(), program.len(), &mut machine);
+    assert_eq!(result, -5);
+    assert_eq!(machine.accumulator, -5);
+    assert_eq!(machine.steps, 4);
+    assert!(<Counter as UnionFn>::ARGS_SIZE >= 9);
+
+    assert_eq!(MathFn::add(1, 2).into_opt().call(), 3);
+
+    let mut memory = [0; 4];
+    unsafe { Memory::store(2, 42).into_opt().call(&mut memory) };
+    assert_eq!(memory, [0, 0, 42, 0]);
+}
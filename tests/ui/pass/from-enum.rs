@@ -0,0 +1,33 @@
+use union_fn::{CallWithContext as _, IntoOpt};
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Doubles the value.
+    fn double(value: &mut Self::Context) {
+        *value *= 2;
+    }
+}
+
+type CounterOpt = <Counter as IntoOpt>::Opt;
+
+fn main() {
+    let instrs = vec![Counter::bump_by(1), Counter::double(), Counter::bump_by(3)];
+    let compiled = instrs.into_iter().map(Into::into).collect::<Vec<CounterOpt>>();
+    let mut value = 0;
+    for instr in compiled {
+        instr.call(&mut value);
+    }
+    assert_eq!(value, 5);
+    let instr: CounterOpt = Counter::double().into();
+    instr.call(&mut value);
+    assert_eq!(value, 10);
+    CounterOpt::from(Counter::bump_by(2)).call(&mut value);
+    assert_eq!(value, 12);
+}
@@ -0,0 +1,32 @@
+use union_fn::CallWithContext as _;
+
+#[union_fn::union_fn(module = "counter_impl")]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+use counter_impl::{CounterArgs, CounterDelegate, CounterOpt};
+
+fn main() {
+    let mut value = 0;
+    let bump_by = unsafe {
+        CounterOpt::from_raw(0, CounterDelegate::bump_by, CounterArgs::bump_by(5))
+    };
+    assert_eq!(bump_by.handler_ptr(), CounterOpt::bump_by(5).handler_ptr());
+    bump_by.call(&mut value);
+    bump_by.call(&mut value);
+    assert_eq!(value, 10);
+    let reset = unsafe { CounterOpt::from_raw(1, CounterDelegate::reset, CounterArgs::reset()) };
+    reset.call(&mut value);
+    assert_eq!(value, 0);
+}
@@ -0,0 +1,24 @@
+use union_fn::CallWithContext as _;
+
+#[union_fn::union_fn(max_args_size = 16)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = if *value == 0 { lhs } else { rhs };
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    Counter::select(1, 2).call(&mut value);
+    assert_eq!(value, 1);
+    Counter::bump_by(41).call(&mut value);
+    assert_eq!(value, 42);
+}
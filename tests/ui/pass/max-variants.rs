@@ -0,0 +1,40 @@
+use union_fn::CallWithContext as _;
+
+#[union_fn::union_fn(max_variants = 2)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Disabled methods do not count towards max_variants.
+    #[cfg(any())]
+    fn double(value: &mut Self::Context) {
+        *value *= 2;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+#[union_fn::union_fn(no_opt, max_variants = "1")]
+trait Answer {
+    type Output = i32;
+
+    fn answer() -> Self::Output {
+        42
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    Counter::bump_by(42).call(&mut value);
+    assert_eq!(value, 42);
+    Counter::reset().call(&mut value);
+    assert_eq!(value, 0);
+    assert_eq!(union_fn::Call::call(Answer::answer()), 42);
+}
@@ -0,0 +1,41 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn(module = "counter_impl")]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+use counter_impl::{CounterArgs, CounterOpt};
+
+trait Describe {
+    fn describe(&self) -> &'static str;
+}
+
+impl Describe for CounterOpt {
+    fn describe(&self) -> &'static str {
+        "compiled counter instruction"
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    let bump_by: CounterOpt = Counter::bump_by(2).into_opt();
+    bump_by.call(&mut value);
+    CounterOpt::bump_by(3).call(&mut value);
+    assert_eq!(value, 5);
+    CounterOpt::reset().call(&mut value);
+    assert_eq!(value, 0);
+    assert_eq!(bump_by.describe(), "compiled counter instruction");
+    let args: &CounterArgs = bump_by.args_ref();
+    assert_eq!(unsafe { args.bump_by }, 2);
+}
@@ -0,0 +1,61 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[derive(Default)]
+pub struct Memory {
+    bytes: [u8; 8],
+}
+
+#[union_fn::union_fn]
+#[derive(Debug)]
+trait Instr {
+    type Context = Memory;
+
+    /// Fills `count` bytes starting at `offset` with `value`.
+    #[named_args]
+    fn fill(memory: &mut Self::Context, offset: usize, count: usize, value: u8) {
+        memory.bytes[offset..offset + count].fill(value);
+    }
+
+    /// Clears all bytes.
+    fn clear(memory: &mut Self::Context) {
+        memory.bytes = [0; 8];
+    }
+}
+
+#[union_fn::union_fn(no_enum)]
+trait Counter {
+    type Context = i64;
+
+    #[named_args]
+    fn bump_by(value: &mut Self::Context, by: i64, times: i64) {
+        *value += by * times;
+    }
+}
+
+fn main() {
+    let args = FillArgs {
+        offset: 2,
+        count: 3,
+        value: 7,
+    };
+    let instr = Instr::fill(args);
+    assert!(matches!(
+        instr,
+        Instr::Fill {
+            offset: 2,
+            count: 3,
+            value: 7
+        }
+    ));
+
+    let mut memory = Memory::default();
+    instr.call(&mut memory);
+    assert_eq!(memory.bytes, [0, 0, 7, 7, 7, 0, 0, 0]);
+    Instr::clear().into_opt().call(&mut memory);
+    Instr::fill(FillArgs { value: 1, ..args }).into_opt().call(&mut memory);
+    assert_eq!(memory.bytes, [0, 0, 1, 1, 1, 0, 0, 0]);
+
+    let mut value = 0;
+    CounterOpt::bump_by(BumpByArgs { by: 2, times: 3 }).call(&mut value);
+    assert_eq!(value, 6);
+}
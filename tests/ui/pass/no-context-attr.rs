@@ -0,0 +1,57 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn]
+trait Calc {
+    type Context = i32;
+    type Output = i32;
+
+    /// Adds `rhs` to the value.
+    fn add(value: &mut Self::Context, rhs: i32) -> Self::Output {
+        *value += rhs;
+        *value
+    }
+
+    /// Multiplies `a` and `b` without touching the value.
+    #[no_context]
+    fn multiply(a: i32, b: i32) -> Self::Output {
+        a * b
+    }
+
+    /// Returns one without touching the value.
+    #[no_context]
+    fn one() -> Self::Output {
+        1
+    }
+}
+
+#[union_fn::union_fn(no_opt)]
+trait Inline {
+    type Context = i32;
+    type Output = i32;
+
+    /// Returns the value.
+    fn get(value: &Self::Context) -> Self::Output {
+        *value
+    }
+
+    /// Returns the `constant`.
+    #[no_context]
+    fn constant(constant: i32) -> Self::Output {
+        constant
+    }
+}
+
+fn main() {
+    let mut value = 1;
+
+    assert_eq!(Calc::add(2).call(&mut value), 3);
+    assert_eq!(Calc::multiply(6, 7).call(&mut value), 42);
+    assert_eq!(Calc::one().call(&mut value), 1);
+    assert_eq!(Calc::multiply(2, 3).into_opt().call(&mut value), 6);
+    assert_eq!(Calc::add(1).into_opt().call(&mut value), 4);
+    assert_eq!(<Calc as Default>::default().call(&mut value), 1);
+    assert_eq!(value, 4);
+
+    assert_eq!(Inline::get().call(&mut value), 4);
+    assert_eq!(Inline::constant(5).call(&mut value), 5);
+}
@@ -0,0 +1,30 @@
+use union_fn::CallWithContext as _;
+
+/// Only the call optimized `CounterOpt` type is generated.
+#[union_fn::union_fn(no_enum)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    let instrs = [CounterOpt::bump_by(2), CounterOpt::bump_by(3)];
+    for instr in instrs {
+        instr.call(&mut value);
+    }
+    assert_eq!(value, 5);
+    CounterOpt::reset().call(&mut value);
+    assert_eq!(value, 0);
+    assert_eq!(CounterOpt::VARIANT_COUNT, 2);
+    assert_eq!(COUNTER_VARIANT_NAMES, ["bump_by", "reset"]);
+}
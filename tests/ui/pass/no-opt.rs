@@ -0,0 +1,46 @@
+use union_fn::{CallWithContext as _, UnionFn};
+
+/// Only the `Counter` enum with match-based dispatch is generated.
+#[union_fn::union_fn(no_opt)]
+trait Counter {
+    type Context = i64;
+    type Output = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) -> Self::Output {
+        *value += by;
+        *value
+    }
+
+    /// Bumps the value `by` the amount twice.
+    fn bump_twice(ctx: &mut Self::Context, mut by: i64) -> Self::Output {
+        by *= 2;
+        *ctx += by;
+        *ctx
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, _: i64) -> Self::Output {
+        if *value != 0 {
+            return *value;
+        }
+        *value = lhs;
+        *value
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(_value: &mut Self::Context) -> Self::Output {
+        0
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    assert_eq!(Counter::select(5, 6).call(&mut value), 5);
+    assert_eq!(Counter::select(7, 8).call(&mut value), 5);
+    assert_eq!(Counter::bump_by(2).call(&mut value), 7);
+    assert_eq!(Counter::bump_twice(3).call(&mut value), 13);
+    assert_eq!(Counter::reset().call(&mut value), 0);
+    assert_eq!(Counter::ARGS_SIZE, 0);
+    assert_eq!(Counter::default().variant_name(), "reset");
+}
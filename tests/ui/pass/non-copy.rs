@@ -0,0 +1,40 @@
+use union_fn::CallWithContext as _;
+
+#[union_fn::union_fn(no_opt)]
+trait Logger {
+    type Context = Vec<String>;
+
+    /// Logs the `message`.
+    fn log(log: &mut Self::Context, message: String) {
+        log.push(message);
+    }
+
+    /// Logs the `message` repeated `count` times.
+    fn log_repeated(log: &mut Self::Context, message: String, count: usize) {
+        log.push(message.repeat(count));
+    }
+
+    /// Logs the length of the large `blob`.
+    fn log_blob(log: &mut Self::Context, blob: Box<[u8; 32]>) {
+        log.push(blob.len().to_string());
+    }
+
+    /// Clears the log.
+    fn clear(log: &mut Self::Context) {
+        log.clear();
+    }
+}
+
+fn main() {
+    let mut log = Vec::new();
+    let instr = Logger::log(String::from("hello"));
+    instr.clone().call(&mut log);
+    assert_eq!(instr.as_log(), Some(String::from("hello")));
+    let instr = Logger::log_repeated(String::from("ab"), 2);
+    assert_eq!(instr.as_log_repeated(), Some((String::from("ab"), 2)));
+    instr.call(&mut log);
+    Logger::log_blob(Box::new([0; 32])).call(&mut log);
+    assert_eq!(log, ["hello", "abab", "32"]);
+    Logger::clear().call(&mut log);
+    assert!(log.is_empty());
+}
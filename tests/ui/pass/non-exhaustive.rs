@@ -0,0 +1,29 @@
+use union_fn::CallWithContext as _;
+
+#[union_fn::union_fn(non_exhaustive)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    Counter::bump_by(42).call(&mut value);
+    assert_eq!(value, 42);
+    Counter::reset().call(&mut value);
+    assert_eq!(value, 0);
+    // Within the defining crate matching on the enum can still be exhaustive.
+    match Counter::reset() {
+        Counter::BumpBy { .. } => unreachable!(),
+        Counter::Reset {} => {}
+    }
+}
@@ -0,0 +1,35 @@
+use union_fn::IntoOpt;
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = if *value == 0 { lhs } else { rhs };
+    }
+}
+
+fn main() {
+    let bump_by = Counter::bump_by(42).into_opt();
+    assert_eq!(unsafe { bump_by.args_ref().bump_by }, 42);
+    let select = Counter::select(1, 2).into_opt();
+    assert_eq!(unsafe { select.args_ref().select }, (1, 2));
+    assert_eq!(unsafe { bump_by.args_ref().args_as_bump_by() }, 42);
+    assert_eq!(unsafe { select.args_ref().args_as_select() }, (1, 2));
+
+    type Delegator = <Counter as IntoOpt>::Delegator;
+    assert_eq!(bump_by.handler_ptr(), Delegator::bump_by as *const ());
+    assert_eq!(select.handler_ptr(), Delegator::select as *const ());
+    assert_ne!(bump_by.handler_ptr(), select.handler_ptr());
+    assert_eq!(
+        format!("{bump_by:p}"),
+        format!("{:p}", Delegator::bump_by as *const ()),
+    );
+    assert_ne!(format!("{bump_by:p}"), format!("{select:p}"));
+}
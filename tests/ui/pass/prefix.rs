@@ -0,0 +1,26 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+/// User defined type that would collide with the default name of the call optimized type.
+pub struct CounterOpt;
+
+#[union_fn::union_fn(prefix = "__", module = "counter_impl")]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+use counter_impl::{__CounterArgs, __CounterOpt};
+
+fn main() {
+    let _ = CounterOpt;
+    let mut value = 0;
+    let bump_by: __CounterOpt = Counter::bump_by(2).into_opt();
+    bump_by.call(&mut value);
+    assert_eq!(value, 2);
+    let args: &__CounterArgs = bump_by.args_ref();
+    assert_eq!(unsafe { args.args_as_bump_by() }, 2);
+}
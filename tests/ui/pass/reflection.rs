@@ -0,0 +1,85 @@
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = if *value == 0 { lhs } else { rhs };
+    }
+
+    /// Divides the `value` by 2.
+    fn div2(value: &mut Self::Context) {
+        *value /= 2;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {
+    assert_eq!(Counter::bump_by(1).variant_name(), "bump_by");
+    assert_eq!(Counter::div2().variant_name(), "div2");
+    assert_eq!(Counter::reset().variant_name(), "reset");
+
+    assert_eq!(Counter::bump_by(1).discriminant(), 0);
+    assert_eq!(Counter::bump_by(2).discriminant(), 0);
+    assert_eq!(Counter::select(1, 2).discriminant(), 1);
+    assert_eq!(Counter::div2().discriminant(), 2);
+    assert_eq!(Counter::reset().discriminant(), 3);
+
+    for instr in [Counter::div2(), Counter::reset()] {
+        let restored = Counter::from_discriminant(instr.discriminant()).unwrap();
+        assert_eq!(restored.variant_name(), instr.variant_name());
+    }
+    assert!(Counter::from_discriminant(0).is_none());
+    assert!(Counter::from_discriminant(1).is_none());
+    assert!(Counter::from_discriminant(4).is_none());
+
+    let zero_arg: Vec<_> = Counter::zero_arg_variants()
+        .map(|instr| instr.variant_name())
+        .collect();
+    assert_eq!(zero_arg, ["div2", "reset"]);
+
+    let variants = Counter::variants();
+    assert_eq!(variants.len(), 4);
+    assert_eq!(Counter::VARIANT_COUNT, 4);
+    for (n, variant) in variants.iter().enumerate() {
+        assert_eq!(variant.discriminant, n);
+    }
+    let names: Vec<_> = variants.iter().map(|variant| variant.name).collect();
+    assert_eq!(names, ["bump_by", "select", "div2", "reset"]);
+    assert_eq!(COUNTER_VARIANT_NAMES, ["bump_by", "select", "div2", "reset"]);
+    assert_eq!(COUNTER_VARIANT_NAMES[Counter::div2().discriminant()], "div2");
+    let sizes: Vec<_> = variants.iter().map(|variant| variant.args_size).collect();
+    assert_eq!(sizes, [8, 16, 0, 0]);
+    assert_eq!(COUNTER_VARIANT_ARG_SIZES, [8, 16, 0, 0]);
+    let program = [Counter::bump_by(1), Counter::reset(), Counter::select(1, 2)];
+    let program_size: usize = program
+        .iter()
+        .map(|instr| 1 + COUNTER_VARIANT_ARG_SIZES[instr.discriminant()])
+        .sum();
+    assert_eq!(program_size, 27);
+
+    assert!(Counter::bump_by(1).is_bump_by());
+    assert!(!Counter::bump_by(1).is_div2());
+    assert!(Counter::div2().is_div2());
+    assert!(Counter::reset().is_reset());
+    assert!(!Counter::reset().is_bump_by());
+
+    assert_eq!(Counter::bump_by(42).as_bump_by(), Some(42));
+    assert_eq!(Counter::bump_by(42).as_reset(), None);
+    assert_eq!(Counter::reset().as_reset(), Some(()));
+    assert_eq!(Counter::reset().as_bump_by(), None);
+    assert_eq!(Counter::select(1, 2).as_select(), Some((1, 2)));
+
+    assert!(Counter::default().is_div2());
+    let buffer = vec![Counter::default(); 3];
+    assert!(buffer.iter().all(Counter::is_div2));
+}
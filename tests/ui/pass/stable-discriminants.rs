@@ -0,0 +1,47 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn(stable_discriminants)]
+trait Counter {
+    type Context = i64;
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = if *value == 0 { lhs } else { rhs };
+    }
+
+    /// Divides the `value` by 2.
+    fn div2(value: &mut Self::Context) {
+        *value /= 2;
+    }
+}
+
+fn main() {
+    assert_eq!(Counter::bump_by(1).discriminant(), 0);
+    assert_eq!(Counter::div2().discriminant(), 1);
+    assert_eq!(Counter::reset().discriminant(), 2);
+    assert_eq!(Counter::select(1, 2).discriminant(), 3);
+    assert_eq!(COUNTER_VARIANT_NAMES, ["bump_by", "div2", "reset", "select"]);
+    // `Default` is the first method without parameters in declaration order.
+    assert!(matches!(Counter::default(), Counter::Reset {}));
+    assert!(matches!(
+        Counter::zero_arg_variants().collect::<Vec<_>>()[..],
+        [Counter::Div2 {}, Counter::Reset {}]
+    ));
+
+    let mut value = 0;
+    Counter::bump_by(42).into_opt().call(&mut value);
+    Counter::div2().into_opt().call(&mut value);
+    assert_eq!(value, 21);
+    Counter::reset().call(&mut value);
+    assert_eq!(value, 0);
+}
@@ -0,0 +1,46 @@
+use union_fn::{IntoOpt as _, TryCall as _, TryCallWithContext as _};
+
+#[union_fn::union_fn]
+trait Checked {
+    fn div(lhs: i32, rhs: i32) -> Result<i32, &'static str> {
+        lhs.checked_div(rhs).ok_or("division by zero")
+    }
+}
+
+#[union_fn::union_fn]
+trait Stack {
+    type Context = Vec<i32>;
+
+    fn push(stack: &mut Self::Context, value: i32) -> Result<(), &'static str> {
+        stack.push(value);
+        Ok(())
+    }
+
+    fn pop(stack: &mut Self::Context) -> Result<(), &'static str> {
+        stack.pop().map(|_| ()).ok_or("stack underflow")
+    }
+}
+
+fn run(stack: &mut Vec<i32>, instrs: &[Stack]) -> Result<(), &'static str> {
+    for instr in instrs {
+        instr.into_opt().try_call(stack)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    assert_eq!(Checked::div(6, 3).try_call(), Ok(2));
+    assert_eq!(Checked::div(6, 0).into_opt().try_call(), Err("division by zero"));
+    assert_eq!(Checked::div(6, 3).call_or(-1), 2);
+    assert_eq!(Checked::div(6, 0).call_or(-1), -1);
+    assert_eq!(Checked::div(6, 0).into_opt().call_or_default(), 0);
+
+    let mut stack = Vec::new();
+    assert_eq!(run(&mut stack, &[Stack::push(1), Stack::pop()]), Ok(()));
+    assert_eq!(run(&mut stack, &[Stack::pop()]), Err("stack underflow"));
+    assert_eq!(Stack::push(2).try_call(&mut stack), Ok(()));
+    assert_eq!(stack, [2]);
+    Stack::pop().call_or(&mut stack, ());
+    Stack::pop().into_opt().call_or_default(&mut stack);
+    assert!(stack.is_empty());
+}
@@ -0,0 +1,72 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn]
+#[derive(Debug)]
+trait Geometry {
+    type Context = (i64, i64);
+
+    /// Translates the position by `(dx, dy)` scaled by `scale`.
+    fn translate(position: &mut Self::Context, (dx, dy): (i32, i32), scale: i64) {
+        position.0 += i64::from(dx) * scale;
+        position.1 += i64::from(dy) * scale;
+    }
+
+    /// Moves the position to the origin and then by `((x, y), z)`.
+    fn reset_to(position: &mut Self::Context, ((x, y), z): ((i32, i32), i32)) {
+        *position = (i64::from(x + z), i64::from(y + z));
+    }
+
+    /// Moves the position by `(x, y)` and then by `x_y` along both axes.
+    fn shift(position: &mut Self::Context, (x, y): (i32, i32), x_y: i32) {
+        position.0 += i64::from(x + x_y);
+        position.1 += i64::from(y + x_y);
+    }
+
+    /// Swaps the coordinates of the position.
+    fn swap(position: &mut Self::Context, (_, _): (u8, u8)) {
+        *position = (position.1, position.0);
+    }
+}
+
+fn main() {
+    assert_eq!(Geometry::zero_arg_variants().count(), 0);
+
+    let instr = Geometry::translate((1, -2), 3);
+    assert!(matches!(
+        instr,
+        Geometry::Translate {
+            dx_dy: (1, -2),
+            scale: 3
+        }
+    ));
+    assert!(matches!(
+        Geometry::reset_to(((1, 2), 3)),
+        Geometry::ResetTo {
+            x_y_z: ((1, 2), 3)
+        }
+    ));
+    // The tuple pattern falls back to `_N` since `x_y` is already taken.
+    assert!(matches!(
+        Geometry::shift((1, 2), 3),
+        Geometry::Shift {
+            _0: (1, 2),
+            x_y: 3
+        }
+    ));
+    assert_eq!(
+        format!("{:?}", Geometry::swap((0, 0))),
+        "Swap { _0: (0, 0) }"
+    );
+
+    let mut position = (0, 0);
+    instr.call(&mut position);
+    assert_eq!(position, (3, -6));
+    instr.into_opt().call(&mut position);
+    assert_eq!(position, (6, -12));
+    Geometry::swap((0, 0)).into_opt().call(&mut position);
+    assert_eq!(position, (-12, 6));
+    Geometry::reset_to(((1, 2), 3)).into_opt().call(&mut position);
+    assert_eq!(position, (4, 5));
+    Geometry::shift((1, 2), 3).into_opt().call(&mut position);
+    assert_eq!(position, (8, 10));
+}
@@ -0,0 +1,41 @@
+use union_fn::{CallWithContext as _, IntoOpt, UnionFn};
+
+#[union_fn::union_fn(
+    opt_name = "CompiledCounter",
+    args_name = "CounterParams",
+    delegate_name = "CounterDispatch"
+)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+/// Only the call optimized `CompiledInstr` type is generated.
+#[union_fn::union_fn(no_enum, opt_name = "CompiledInstr")]
+trait Instr {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    Counter::bump_by(2).into_opt().call(&mut value);
+    CompiledInstr::bump_by(3).call(&mut value);
+    assert_eq!(value, 5);
+
+    fn type_name_of<T>() -> &'static str {
+        std::any::type_name::<T>().rsplit("::").next().unwrap()
+    }
+    assert_eq!(type_name_of::<<Counter as IntoOpt>::Opt>(), "CompiledCounter");
+    assert_eq!(type_name_of::<<Counter as UnionFn>::Args>(), "CounterParams");
+    assert_eq!(type_name_of::<<Counter as IntoOpt>::Delegator>(), "CounterDispatch");
+    assert_eq!(type_name_of::<<Counter as IntoOpt>::Impls>(), "CounterImpls");
+}
@@ -0,0 +1,25 @@
+use union_fn::{CallWithContext as _, IntoOpt as _};
+
+#[union_fn::union_fn]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    #[allow(clippy::unused_unit)]
+    fn bump_by(value: &mut Self::Context, by: i64) -> () {
+        *value += by;
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+fn main() {
+    let mut value = 0;
+    Counter::bump_by(5).call(&mut value);
+    assert_eq!(value, 5);
+    Counter::reset().into_opt().call(&mut value);
+    assert_eq!(value, 0);
+}
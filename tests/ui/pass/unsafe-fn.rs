@@ -0,0 +1,56 @@
+use union_fn::{IntoOpt as _, UnsafeCall as _, UnsafeCallWithContext as _};
+
+#[union_fn::union_fn]
+trait Memory {
+    type Context = [u8; 4];
+
+    /// Stores `value` at `index` without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The `index` must be in bounds of the memory.
+    unsafe fn store(memory: &mut Self::Context, index: usize, value: u8) {
+        *memory.get_unchecked_mut(index) = value;
+    }
+
+    /// Zeroes the byte at `index` without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The `index` must be in bounds of the memory.
+    unsafe fn clear(memory: &mut Self::Context, index: usize) {
+        *memory.get_unchecked_mut(index) = 0;
+    }
+}
+
+static DATA: [u8; 4] = [1, 2, 3, 5];
+
+#[union_fn::union_fn]
+trait Read {
+    type Output = u8;
+
+    /// Reads the byte at `index` of [`DATA`] without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The `index` must be in bounds of [`DATA`].
+    unsafe fn read(index: usize) -> Self::Output {
+        *DATA.get_unchecked(index)
+    }
+}
+
+fn main() {
+    let mut memory = [0_u8; 4];
+    unsafe {
+        Memory::store(1, 42).call(&mut memory);
+        Memory::store(2, 7).into_opt().call(&mut memory);
+    }
+    assert_eq!(memory, [0, 42, 7, 0]);
+    unsafe {
+        Memory::clear(1).into_opt().call(&mut memory);
+    }
+    assert_eq!(memory, [0, 0, 7, 0]);
+
+    assert_eq!(unsafe { Read::read(3).call() }, 5);
+    assert_eq!(unsafe { Read::read(3).into_opt().call() }, 5);
+}
@@ -0,0 +1,55 @@
+#[union_fn::union_fn(visitor)]
+trait Counter {
+    type Context = i64;
+
+    /// Bumps the value `by` the amount.
+    fn bump_by(value: &mut Self::Context, by: i64) {
+        *value += by;
+    }
+
+    /// Selects `lhs` if `value` is zero and `rhs` otherwise.
+    fn select(value: &mut Self::Context, lhs: i64, rhs: i64) {
+        *value = if *value == 0 { lhs } else { rhs };
+    }
+
+    /// Resets the `value` to zero.
+    fn reset(value: &mut Self::Context) {
+        *value = 0;
+    }
+}
+
+/// Computes the largest constant used by the visited instructions.
+#[derive(Default)]
+struct MaxConstant {
+    visited: usize,
+}
+
+impl CounterVisitor for MaxConstant {
+    type Output = Option<i64>;
+
+    fn visit_bump_by(&mut self, by: i64) -> Self::Output {
+        self.visited += 1;
+        Some(by)
+    }
+
+    fn visit_select(&mut self, lhs: i64, rhs: i64) -> Self::Output {
+        self.visited += 1;
+        Some(lhs.max(rhs))
+    }
+
+    fn visit_reset(&mut self) -> Self::Output {
+        self.visited += 1;
+        None
+    }
+}
+
+fn main() {
+    let instrs = [Counter::bump_by(5), Counter::select(10, -3), Counter::reset()];
+    let mut visitor = MaxConstant::default();
+    let max = instrs
+        .into_iter()
+        .filter_map(|instr| instr.visit(&mut visitor))
+        .max();
+    assert_eq!(max, Some(10));
+    assert_eq!(visitor.visited, 3);
+}
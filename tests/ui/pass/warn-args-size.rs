@@ -0,0 +1,24 @@
+#![deny(deprecated)]
+
+use union_fn::{Call as _, IntoOpt as _, UnionFn as _};
+
+#[union_fn::union_fn(warn_args_size = 16)]
+trait Foo {
+    type Output = u64;
+
+    /// Returns the `value`.
+    fn small(value: u8) -> Self::Output {
+        value as u64
+    }
+
+    /// Returns the sum of the `values`.
+    fn large(values: [u64; 2]) -> Self::Output {
+        values[0] + values[1]
+    }
+}
+
+fn main() {
+    assert_eq!(<Foo as union_fn::IntoOpt>::Opt::ARGS_SIZE, 16);
+    assert_eq!(Foo::small(1).into_opt().call(), 1);
+    assert_eq!(Foo::large([1, 2]).into_opt().call(), 3);
+}